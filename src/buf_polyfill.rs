@@ -29,3 +29,62 @@ pub fn copy_to_uninit_slice<'a>(
     // SAFETY: we have initilaised all of the bytes
     Some(unsafe { mu_polyfill::slice_assume_init_mut(dst) })
 }
+
+/// Like [`copy_to_uninit_slice`] but does not advance the buffer, and skips the first `offset` bytes.
+///
+/// Only the bytes visible without advancing can be copied. This is the first [`Buf::chunk`],
+/// or every chunk returned by [`Buf::chunks_vectored`] with the `std` feature.
+/// If there are not enough, the number of visible bytes is returned instead.
+pub fn peek_to_uninit_slice<'a>(
+    this: &impl Buf,
+    offset: usize,
+    dst: &'a mut [mem::MaybeUninit<u8>],
+) -> Result<&'a mut [u8], usize> {
+    let mut skip = offset;
+    let mut visible = 0;
+
+    let mut c = &mut *dst;
+    for_each_visible_chunk(this, |src| {
+        visible += src.len();
+
+        let n = usize::min(skip, src.len());
+        let src = &src[n..];
+        skip -= n;
+
+        let cnt = usize::min(src.len(), c.len());
+        let (head, tail) = mem::take(&mut c).split_at_mut(cnt);
+        mu_polyfill::copy_from_slice(head, &src[..cnt]);
+        c = tail;
+
+        !c.is_empty()
+    });
+
+    if !c.is_empty() {
+        return Err(visible);
+    }
+
+    // SAFETY: we have initilaised all of the bytes
+    Ok(unsafe { mu_polyfill::slice_assume_init_mut(dst) })
+}
+
+/// The most chunks that will be looked at by [`peek_to_uninit_slice`].
+#[cfg(feature = "std")]
+const MAX_VISIBLE_CHUNKS: usize = 64;
+
+/// Call `f` on each chunk that is visible without advancing, until it returns false.
+#[cfg(feature = "std")]
+fn for_each_visible_chunk(this: &impl Buf, mut f: impl FnMut(&[u8]) -> bool) {
+    let mut chunks = [std::io::IoSlice::new(&[]); MAX_VISIBLE_CHUNKS];
+    let n = this.chunks_vectored(&mut chunks);
+    for chunk in &chunks[..n] {
+        if !f(chunk) {
+            return;
+        }
+    }
+}
+
+/// Call `f` on each chunk that is visible without advancing, until it returns false.
+#[cfg(not(feature = "std"))]
+fn for_each_visible_chunk(this: &impl Buf, mut f: impl FnMut(&[u8]) -> bool) {
+    f(this.chunk());
+}
@@ -0,0 +1,127 @@
+//! A UTF-8 string backed by [`Bytes`].
+
+use core::{borrow::Borrow, fmt, hash, ops::Deref, str};
+
+use bytes::Bytes;
+
+/// A [`Bytes`] that is guaranteed to be valid UTF-8.
+///
+/// Like [`Bytes`], cloning is cheap and the string can outlive the buffer it was read from.
+///
+/// ```
+/// use zerocopy_buf::{ByteStr, ZeroCopyBuf};
+///
+/// let mut data = bytes::Bytes::from_static(b"hello world");
+/// let hello: ByteStr = data.try_get_str(5).unwrap();
+/// assert_eq!(hello, "hello");
+/// assert_eq!(hello.to_uppercase(), "HELLO");
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ByteStr(Bytes);
+
+impl ByteStr {
+    /// Create a string that points to static memory.
+    pub const fn from_static(s: &'static str) -> Self {
+        ByteStr(Bytes::from_static(s.as_bytes()))
+    }
+
+    /// Validate that `bytes` is UTF-8.
+    pub fn from_utf8(bytes: Bytes) -> Result<Self, str::Utf8Error> {
+        str::from_utf8(&bytes)?;
+        Ok(ByteStr(bytes))
+    }
+
+    /// Wrap `bytes` without checking that it is UTF-8.
+    ///
+    /// # Safety
+    ///
+    /// `bytes` must be valid UTF-8.
+    pub const unsafe fn from_utf8_unchecked(bytes: Bytes) -> Self {
+        ByteStr(bytes)
+    }
+
+    /// The string as a `&str`.
+    pub fn as_str(&self) -> &str {
+        // SAFETY: the bytes were validated when the ByteStr was created.
+        unsafe { str::from_utf8_unchecked(&self.0) }
+    }
+
+    /// The underlying [`Bytes`].
+    pub fn as_bytes(&self) -> &Bytes {
+        &self.0
+    }
+
+    /// Convert into the underlying [`Bytes`].
+    pub fn into_bytes(self) -> Bytes {
+        self.0
+    }
+}
+
+impl Deref for ByteStr {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for ByteStr {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<[u8]> for ByteStr {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Borrow<str> for ByteStr {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+// must agree with `str`, for `Borrow<str>`.
+impl hash::Hash for ByteStr {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl PartialEq<str> for ByteStr {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for ByteStr {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl From<&'static str> for ByteStr {
+    fn from(s: &'static str) -> Self {
+        ByteStr::from_static(s)
+    }
+}
+
+impl From<ByteStr> for Bytes {
+    fn from(s: ByteStr) -> Self {
+        s.0
+    }
+}
+
+impl fmt::Debug for ByteStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for ByteStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
@@ -0,0 +1,258 @@
+//! Integer types with widths that [`zerocopy::byteorder`] does not provide.
+
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    mem::size_of,
+    ops,
+};
+
+use zerocopy::{byteorder::ByteOrder, FromBytes, Immutable, IntoBytes, KnownLayout, Unaligned};
+
+use crate::Endian;
+
+macro_rules! define_int {
+    (
+        $(#[$attr:meta])*
+        $name:ident($native:ident; $bytes:literal),
+        from: [$($from:ident),*],
+        into: [$($into:ident),*]
+    ) => {
+        $(#[$attr])*
+        ///
+        /// Like the types in [`zerocopy::byteorder`], this has no alignment requirement,
+        /// so it can be used directly in structs read with [`ZeroCopyBuf::try_get`](crate::ZeroCopyBuf::try_get).
+        #[derive(FromBytes, IntoBytes, KnownLayout, Immutable, Unaligned)]
+        #[repr(transparent)]
+        pub struct $name<O>([u8; $bytes], PhantomData<O>);
+
+        impl<O> $name<O> {
+            /// The number of bits the value is shifted by inside the native type.
+            const SHIFT: u32 = ((size_of::<$native>() - $bytes) * 8) as u32;
+
+            /// The smallest value that can be represented.
+            pub const MIN: $native = $native::MIN >> Self::SHIFT;
+
+            /// The largest value that can be represented.
+            pub const MAX: $native = $native::MAX >> Self::SHIFT;
+
+            /// The value `0`.
+            pub const ZERO: Self = $name([0; $bytes], PhantomData);
+
+            /// The raw bytes of the value.
+            pub const fn to_bytes(self) -> [u8; $bytes] {
+                self.0
+            }
+
+            /// Create the value from its raw bytes.
+            pub const fn from_bytes(bytes: [u8; $bytes]) -> Self {
+                $name(bytes, PhantomData)
+            }
+        }
+
+        impl<O: ByteOrder> $name<O> {
+            /// Create the value from `n`.
+            ///
+            /// Returns [`None`] if `n` is outside of [`Self::MIN`]`..=`[`Self::MAX`].
+            pub fn new(n: $native) -> Option<Self> {
+                let t = Self::new_truncating(n);
+                (t.get() == n).then_some(t)
+            }
+
+            /// Create the value from `n`, discarding the bits that don't fit.
+            pub fn new_truncating(n: $native) -> Self {
+                let mut bytes = [0; $bytes];
+                match Endian::of::<O>() {
+                    Endian::Big => bytes.copy_from_slice(&(n << Self::SHIFT).to_be_bytes()[..$bytes]),
+                    Endian::Little => bytes.copy_from_slice(
+                        &(n << Self::SHIFT).to_le_bytes()[size_of::<$native>() - $bytes..],
+                    ),
+                }
+                $name(bytes, PhantomData)
+            }
+
+            /// The value as a native integer.
+            pub fn get(self) -> $native {
+                let mut buf = [0; size_of::<$native>()];
+                let n = match Endian::of::<O>() {
+                    Endian::Big => {
+                        buf[..$bytes].copy_from_slice(&self.0);
+                        $native::from_be_bytes(buf)
+                    }
+                    Endian::Little => {
+                        buf[size_of::<$native>() - $bytes..].copy_from_slice(&self.0);
+                        $native::from_le_bytes(buf)
+                    }
+                };
+                n >> Self::SHIFT
+            }
+
+            /// Replace the value with `n`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `n` is outside of [`Self::MIN`]`..=`[`Self::MAX`].
+            pub fn set(&mut self, n: $native) {
+                match Self::new(n) {
+                    Some(t) => *self = t,
+                    None => panic!("{n} is out of range for {}", stringify!($name)),
+                }
+            }
+        }
+
+        impl<O> Clone for $name<O> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<O> Copy for $name<O> {}
+
+        impl<O> Default for $name<O> {
+            fn default() -> Self {
+                Self::ZERO
+            }
+        }
+
+        impl<O> PartialEq for $name<O> {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl<O> Eq for $name<O> {}
+
+        impl<O> Hash for $name<O> {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.0.hash(state);
+            }
+        }
+
+        impl<O: ByteOrder> PartialOrd for $name<O> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl<O: ByteOrder> Ord for $name<O> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.get().cmp(&other.get())
+            }
+        }
+
+        impl<O: ByteOrder> fmt::Debug for $name<O> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple(stringify!($name)).field(&self.get()).finish()
+            }
+        }
+
+        impl<O: ByteOrder> fmt::Display for $name<O> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.get(), f)
+            }
+        }
+
+        $(
+            impl<O: ByteOrder> From<$from> for $name<O> {
+                fn from(n: $from) -> Self {
+                    Self::new_truncating(n.into())
+                }
+            }
+        )*
+
+        $(
+            impl<O: ByteOrder> From<$name<O>> for $into {
+                fn from(n: $name<O>) -> Self {
+                    n.get().into()
+                }
+            }
+        )*
+
+        impl_arith!($name, Add::add, AddAssign::add_assign, overflowing_add, "add");
+        impl_arith!($name, Sub::sub, SubAssign::sub_assign, overflowing_sub, "subtract");
+        impl_arith!($name, Mul::mul, MulAssign::mul_assign, overflowing_mul, "multiply");
+        impl_arith!($name, Div::div, DivAssign::div_assign, overflowing_div, "divide");
+        impl_arith!($name, Rem::rem, RemAssign::rem_assign, overflowing_rem, "calculate the remainder");
+
+        impl_bits!($name, BitAnd::bitand, BitAndAssign::bitand_assign, &);
+        impl_bits!($name, BitOr::bitor, BitOrAssign::bitor_assign, |);
+        impl_bits!($name, BitXor::bitxor, BitXorAssign::bitxor_assign, ^);
+    };
+}
+
+/// Like the native integers, overflow panics with debug assertions and wraps without them.
+macro_rules! impl_arith {
+    ($name:ident, $op:ident::$method:ident, $op_assign:ident::$method_assign:ident, $overflowing:ident, $verb:literal) => {
+        impl<O: ByteOrder> ops::$op for $name<O> {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self {
+                let (n, overflow) = self.get().$overflowing(rhs.get());
+                let t = Self::new_truncating(n);
+                debug_assert!(
+                    !overflow && t.get() == n,
+                    concat!("attempt to ", $verb, " with overflow")
+                );
+                t
+            }
+        }
+
+        impl<O: ByteOrder> ops::$op_assign for $name<O> {
+            fn $method_assign(&mut self, rhs: Self) {
+                *self = ops::$op::$method(*self, rhs);
+            }
+        }
+    };
+}
+
+macro_rules! impl_bits {
+    ($name:ident, $op:ident::$method:ident, $op_assign:ident::$method_assign:ident, $tt:tt) => {
+        impl<O> ops::$op for $name<O> {
+            type Output = Self;
+
+            fn $method(mut self, rhs: Self) -> Self {
+                ops::$op_assign::$method_assign(&mut self, rhs);
+                self
+            }
+        }
+
+        impl<O> ops::$op_assign for $name<O> {
+            fn $method_assign(&mut self, rhs: Self) {
+                for (a, b) in self.0.iter_mut().zip(rhs.0) {
+                    *a = *a $tt b;
+                }
+            }
+        }
+    };
+}
+
+define_int!(
+    /// A 24-bit unsigned integer stored in the byte order `O`,
+    /// such as the length of an HTTP/2 frame or a TLS handshake message.
+    U24(u32; 3),
+    from: [u8, u16],
+    into: [u32, u64, i64]
+);
+
+define_int!(
+    /// A 24-bit signed integer stored in the byte order `O`.
+    I24(i32; 3),
+    from: [u8, i8, u16, i16],
+    into: [i32, i64]
+);
+
+define_int!(
+    /// A 48-bit unsigned integer stored in the byte order `O`.
+    U48(u64; 6),
+    from: [u8, u16, u32],
+    into: [u64, u128, i128]
+);
+
+define_int!(
+    /// A 48-bit signed integer stored in the byte order `O`.
+    I48(i64; 6),
+    from: [u8, i8, u16, i16, u32, i32],
+    into: [i64, i128]
+);
@@ -0,0 +1,89 @@
+//! NUL-terminated strings backed by the source buffer.
+
+use core::{ffi::CStr, fmt};
+
+use crate::{Error, ZeroCopyBuf};
+
+/// A NUL-terminated string split off a [`ZeroCopyBuf`], from
+/// [`ZeroCopyBuf::try_get_cstr`] or [`ZeroCopyBuf::try_get_cstr_in`].
+///
+/// The bytes are not copied: `B` is the same kind of buffer as the source, such as [`Bytes`](bytes::Bytes).
+///
+/// ```
+/// use zerocopy_buf::ZeroCopyBuf;
+///
+/// let mut data = bytes::Bytes::from_static(b"eth0\0lo\0");
+/// let name = data.try_get_cstr().unwrap();
+/// assert_eq!(name.to_bytes(), b"eth0");
+/// assert_eq!(name.as_cstr().unwrap(), c"eth0");
+/// assert_eq!(name.into_bytes(), b"eth0"[..]);
+/// assert_eq!(data, b"lo\0"[..]);
+/// ```
+#[derive(Clone)]
+pub struct CStrBuf<B> {
+    /// The bytes that were consumed, including the terminator and any padding.
+    buf: B,
+    /// The length of the string, without the terminator.
+    len: usize,
+}
+
+impl<B: ZeroCopyBuf> CStrBuf<B> {
+    /// The string, without the terminator.
+    pub fn to_bytes(&self) -> &[u8] {
+        &self.buf.chunk()[..self.len]
+    }
+
+    /// The string as a [`CStr`].
+    ///
+    /// This is always [`Some`] for strings from [`ZeroCopyBuf::try_get_cstr`],
+    /// but [`None`] if a field from [`ZeroCopyBuf::try_get_cstr_in`] had no terminator.
+    pub fn as_cstr(&self) -> Option<&CStr> {
+        CStr::from_bytes_until_nul(self.buf.chunk()).ok()
+    }
+
+    /// The string, without the terminator, as a buffer of the same kind as the source.
+    pub fn into_bytes(mut self) -> B {
+        self.buf
+            .try_split_section(self.len)
+            .expect("the string is always within the buffer")
+    }
+
+    /// All the bytes that were consumed, including the terminator and any padding after it.
+    pub fn into_inner(self) -> B {
+        self.buf
+    }
+}
+
+impl<B: ZeroCopyBuf> fmt::Debug for CStrBuf<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{}\"", self.to_bytes().escape_ascii())
+    }
+}
+
+/// Split a string off `buf`. If `width` is set, exactly `width` bytes are consumed
+/// and the terminator is optional.
+///
+/// Every error is created here, so the offset is relative to the start of `buf`.
+pub(crate) fn get<B: ZeroCopyBuf>(buf: &mut B, width: Option<usize>) -> Result<CStrBuf<B>, Error> {
+    let available = buf.remaining();
+    let (len, size) = match width {
+        None => {
+            let len = find_nul(buf.chunk()).ok_or(Error::size::<CStr>(available + 1, available))?;
+            (len, len + 1)
+        }
+        Some(width) => {
+            let field = buf
+                .chunk()
+                .get(..width)
+                .ok_or(Error::size::<CStr>(width, available))?;
+            (find_nul(field).unwrap_or(width), width)
+        }
+    };
+
+    let buf = buf.try_split_section(size)?;
+    Ok(CStrBuf { buf, len })
+}
+
+fn find_nul(bytes: &[u8]) -> Option<usize> {
+    bytes.iter().position(|&b| b == 0)
+}
@@ -0,0 +1,138 @@
+//! Byte order chosen at runtime, for formats that declare it in their header.
+
+use core::fmt;
+
+use zerocopy::{
+    byteorder::{BigEndian, ByteOrder, LittleEndian},
+    FromBytes, Immutable, IntoBytes, KnownLayout, Unaligned, U16,
+};
+
+use crate::Primitive;
+
+/// A byte order that is only known at runtime.
+///
+/// Formats such as pcap, TIFF and ELF choose their byte order with a magic value at the start of the file.
+/// Read the magic value as a [`DynEndian`], then use [`Endian::from_magic`] to decide how to read the rest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Endian {
+    /// Most significant byte first.
+    Big,
+    /// Least significant byte first.
+    Little,
+}
+
+impl Endian {
+    /// The byte order of the target platform.
+    #[cfg(target_endian = "big")]
+    pub const NATIVE: Endian = Endian::Big;
+    /// The byte order of the target platform.
+    #[cfg(target_endian = "little")]
+    pub const NATIVE: Endian = Endian::Little;
+
+    /// The byte order used by network protocols.
+    pub const NETWORK: Endian = Endian::Big;
+
+    /// The runtime value of the byte order `O`.
+    pub(crate) fn of<O: ByteOrder>() -> Self {
+        match U16::<O>::new(1).as_bytes() {
+            [0, 1] => Endian::Big,
+            _ => Endian::Little,
+        }
+    }
+
+    /// Detect the byte order that `magic` was written in.
+    ///
+    /// Returns [`None`] if `magic` is not equal to `expected` in either byte order.
+    /// If `expected` reads the same in both byte orders, [`Endian::Big`] is returned.
+    ///
+    /// ```
+    /// use zerocopy_buf::{DynEndian, Endian, ZeroCopyBuf};
+    ///
+    /// let mut data: &[u8] = &b"\xd4\xc3\xb2\xa1"[..];
+    /// let magic = data.try_get::<DynEndian<u32>>().unwrap();
+    /// assert_eq!(Endian::from_magic(*magic, 0xa1b2c3d4), Some(Endian::Little));
+    /// assert_eq!(Endian::from_magic(*magic, 0x0a0d0d0a), None);
+    /// ```
+    pub fn from_magic<T: Primitive + PartialEq>(magic: DynEndian<T>, expected: T) -> Option<Self> {
+        [Endian::Big, Endian::Little]
+            .into_iter()
+            .find(|&endian| magic.get(endian) == expected)
+    }
+}
+
+/// A `T` stored in a byte order that is only known at runtime.
+///
+/// This has the same layout as `T`, but no alignment,
+/// so it can be used as a field in structs that are read with [`ZeroCopyBuf::try_get`](crate::ZeroCopyBuf::try_get).
+/// The bytes are only interpreted when the value is accessed with an [`Endian`].
+///
+/// ```
+/// use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout, Unaligned};
+/// use zerocopy_buf::{DynEndian, Endian, ZeroCopyBuf, ZeroCopyBufMut};
+///
+/// #[derive(FromBytes, IntoBytes, KnownLayout, Immutable, Unaligned)]
+/// #[repr(C)]
+/// struct Header {
+///     version: DynEndian<u16>,
+///     len: DynEndian<u32>,
+/// }
+///
+/// let mut data = bytes::BytesMut::new();
+/// data.write(&Header {
+///     version: DynEndian::new(2, Endian::Little),
+///     len: DynEndian::new(64, Endian::Little),
+/// });
+/// assert_eq!(&data, &b"\x02\x00\x40\x00\x00\x00"[..]);
+///
+/// let header = data.try_get::<Header>().unwrap();
+/// assert_eq!(header.version.get(Endian::Little), 2);
+/// assert_eq!(header.len.get(Endian::Big), 0x40000000);
+/// ```
+#[derive(FromBytes, IntoBytes, KnownLayout, Immutable, Unaligned)]
+#[repr(transparent)]
+pub struct DynEndian<T: Primitive>(T::Wrapper<BigEndian>);
+
+impl<T: Primitive> DynEndian<T> {
+    /// Store `t` in the byte order `endian`.
+    pub fn new(t: T, endian: Endian) -> Self {
+        match endian {
+            Endian::Big => DynEndian(t.to_wrapper()),
+            Endian::Little => DynEndian(reorder::<T, _, _>(t.to_wrapper::<LittleEndian>())),
+        }
+    }
+
+    /// Read the value, interpreting the bytes in the byte order `endian`.
+    pub fn get(self, endian: Endian) -> T {
+        match endian {
+            Endian::Big => T::from_wrapper(self.0),
+            Endian::Little => T::from_wrapper(reorder::<T, _, LittleEndian>(self.0)),
+        }
+    }
+
+    /// Replace the value with `t` in the byte order `endian`.
+    pub fn set(&mut self, t: T, endian: Endian) {
+        *self = DynEndian::new(t, endian);
+    }
+}
+
+impl<T: Primitive> Clone for DynEndian<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Primitive> Copy for DynEndian<T> {}
+
+impl<T: Primitive> fmt::Debug for DynEndian<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DynEndian")
+            .field(&self.0.as_bytes())
+            .finish()
+    }
+}
+
+/// Reinterpret the bytes of a wrapper in another byte order.
+fn reorder<T: Primitive, From: ByteOrder, To: ByteOrder>(w: T::Wrapper<From>) -> T::Wrapper<To> {
+    FromBytes::read_from_bytes(w.as_bytes())
+        .expect("the wrappers for every byte order have the same size")
+}
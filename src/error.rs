@@ -0,0 +1,368 @@
+//! The error type shared by every fallible operation in this crate.
+
+use core::{any::type_name, convert::Infallible, fmt, ops::Deref};
+
+use zerocopy::{AlignmentError, ConvertError, KnownLayout, SizeError, TryFromBytes, ValidityError};
+
+use crate::layout;
+
+/// An error from reading a value out of a buffer.
+///
+/// The buffer that was read from is left untouched when an error is returned,
+/// except by the varint reads when the value spans chunks that cannot be seen without advancing.
+///
+/// `offset` is the position of the value in the message. For plain buffers this is relative
+/// to the position of the buffer at the time of the call, [`Tracked`](crate::Tracked) buffers
+/// report the absolute offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Error {
+    /// The buffer did not contain enough bytes.
+    Size {
+        /// The name of the type that was read.
+        type_name: &'static str,
+        /// The offset of the value in the message.
+        offset: usize,
+        /// The number of bytes that were required.
+        needed: usize,
+        /// The number of bytes that were available.
+        available: usize,
+    },
+    /// The buffer was not suitably aligned.
+    Alignment {
+        /// The name of the type that was read.
+        type_name: &'static str,
+        /// The offset of the value in the message.
+        offset: usize,
+    },
+    /// The bytes were not a valid instance of the type.
+    Validity {
+        /// The name of the type that was read.
+        type_name: &'static str,
+        /// The offset of the value in the message.
+        offset: usize,
+    },
+    /// The value was larger than the configured limit.
+    TooLarge {
+        /// The name of the type that was read.
+        type_name: &'static str,
+        /// The offset of the value in the message.
+        offset: usize,
+        /// The size of the value in bytes.
+        size: usize,
+        /// The largest allowed size in bytes.
+        max: usize,
+    },
+    /// The memory for the value could not be allocated.
+    Alloc {
+        /// The name of the type that was read.
+        type_name: &'static str,
+        /// The offset of the value in the message.
+        offset: usize,
+        /// The number of bytes that were requested.
+        size: usize,
+    },
+    /// A section was not fully consumed by its parser.
+    Leftover {
+        /// The name of the type that the leftover bytes are part of.
+        type_name: &'static str,
+        /// The offset of the leftover bytes in the message.
+        offset: usize,
+        /// The number of bytes left over.
+        remaining: usize,
+    },
+    /// A value extended past the end of the section it was read from.
+    ///
+    /// This replaces an [`Error::Size`] from inside
+    /// [`ZeroCopyBuf::with_section`](crate::ZeroCopyBuf::with_section),
+    /// as the whole section is already in the buffer and more bytes will not help.
+    Overrun {
+        /// The name of the type that was read.
+        type_name: &'static str,
+        /// The offset of the value in the message.
+        offset: usize,
+        /// The number of bytes that were required.
+        needed: usize,
+        /// The number of bytes left in the section.
+        available: usize,
+    },
+    /// The value spans chunks of the buffer that cannot be seen without advancing it,
+    /// so it could not be validated without consuming it.
+    ///
+    /// See [`ZeroCopyReadBuf::try_read_checked`](crate::ZeroCopyReadBuf::try_read_checked).
+    Fragmented {
+        /// The name of the type that was read.
+        type_name: &'static str,
+        /// The offset of the value in the message.
+        offset: usize,
+        /// The size of the value in bytes.
+        size: usize,
+    },
+    /// The bytes were not valid UTF-8.
+    Utf8 {
+        /// The name of the type that was read.
+        type_name: &'static str,
+        /// The offset of the value in the message.
+        offset: usize,
+        /// The number of valid bytes before the first invalid byte.
+        valid_up_to: usize,
+    },
+}
+
+impl Error {
+    pub(crate) fn size<T: ?Sized>(needed: usize, available: usize) -> Self {
+        Error::Size {
+            type_name: type_name::<T>(),
+            offset: 0,
+            needed,
+            available,
+        }
+    }
+
+    pub(crate) fn alignment<T: ?Sized>() -> Self {
+        Error::Alignment {
+            type_name: type_name::<T>(),
+            offset: 0,
+        }
+    }
+
+    pub(crate) fn validity<T: ?Sized>() -> Self {
+        Error::Validity {
+            type_name: type_name::<T>(),
+            offset: 0,
+        }
+    }
+
+    pub(crate) fn too_large<T: ?Sized>(size: usize, max: usize) -> Self {
+        Error::TooLarge {
+            type_name: type_name::<T>(),
+            offset: 0,
+            size,
+            max,
+        }
+    }
+
+    pub(crate) fn alloc<T: ?Sized>(size: usize) -> Self {
+        Error::Alloc {
+            type_name: type_name::<T>(),
+            offset: 0,
+            size,
+        }
+    }
+
+    pub(crate) fn leftover<T: ?Sized>(remaining: usize) -> Self {
+        Error::Leftover {
+            type_name: type_name::<T>(),
+            offset: 0,
+            remaining,
+        }
+    }
+
+    pub(crate) fn fragmented<T: ?Sized>(size: usize) -> Self {
+        Error::Fragmented {
+            type_name: type_name::<T>(),
+            offset: 0,
+            size,
+        }
+    }
+
+    pub(crate) fn utf8<T: ?Sized>(valid_up_to: usize) -> Self {
+        Error::Utf8 {
+            type_name: type_name::<T>(),
+            offset: 0,
+            valid_up_to,
+        }
+    }
+
+    /// Turn an [`Error::Size`] from inside a section into an [`Error::Overrun`].
+    pub(crate) fn overrun(self) -> Self {
+        match self {
+            Error::Size {
+                type_name,
+                offset,
+                needed,
+                available,
+            } => Error::Overrun {
+                type_name,
+                offset,
+                needed,
+                available,
+            },
+            e => e,
+        }
+    }
+
+    /// Fix up the size needed by a DST with `count` elements.
+    pub(crate) fn with_elems<T: KnownLayout<PointerMetadata = usize> + ?Sized>(
+        mut self,
+        count: usize,
+    ) -> Self {
+        if let Error::Size { needed, .. } = &mut self {
+            *needed = layout::size_of::<T>(count).unwrap_or(usize::MAX);
+        }
+        self
+    }
+
+    /// Shift the offset of the error by `n` bytes.
+    pub(crate) fn at(mut self, n: usize) -> Self {
+        match &mut self {
+            Error::Size { offset, .. }
+            | Error::Alignment { offset, .. }
+            | Error::Validity { offset, .. }
+            | Error::TooLarge { offset, .. }
+            | Error::Alloc { offset, .. }
+            | Error::Leftover { offset, .. }
+            | Error::Overrun { offset, .. }
+            | Error::Fragmented { offset, .. }
+            | Error::Utf8 { offset, .. } => *offset = offset.saturating_add(n),
+        }
+        self
+    }
+
+    /// The name of the type that could not be read.
+    pub fn type_name(&self) -> &'static str {
+        match *self {
+            Error::Size { type_name, .. }
+            | Error::Alignment { type_name, .. }
+            | Error::Validity { type_name, .. }
+            | Error::TooLarge { type_name, .. }
+            | Error::Alloc { type_name, .. }
+            | Error::Leftover { type_name, .. }
+            | Error::Overrun { type_name, .. }
+            | Error::Fragmented { type_name, .. }
+            | Error::Utf8 { type_name, .. } => type_name,
+        }
+    }
+
+    /// The offset of the value in the message.
+    pub fn offset(&self) -> usize {
+        match *self {
+            Error::Size { offset, .. }
+            | Error::Alignment { offset, .. }
+            | Error::Validity { offset, .. }
+            | Error::TooLarge { offset, .. }
+            | Error::Alloc { offset, .. }
+            | Error::Leftover { offset, .. }
+            | Error::Overrun { offset, .. }
+            | Error::Fragmented { offset, .. }
+            | Error::Utf8 { offset, .. } => offset,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Error::Size {
+                type_name,
+                offset,
+                needed,
+                available,
+            } => write!(
+                f,
+                "not enough bytes for `{type_name}` at offset {offset}: needed {needed}, but only {available} available"
+            ),
+            Error::Alignment { type_name, offset } => {
+                write!(f, "`{type_name}` at offset {offset} is not suitably aligned")
+            }
+            Error::Validity { type_name, offset } => {
+                write!(f, "the bytes at offset {offset} are not a valid `{type_name}`")
+            }
+            Error::TooLarge {
+                type_name,
+                offset,
+                size,
+                max,
+            } => write!(
+                f,
+                "`{type_name}` at offset {offset} is too large: {size} bytes, but the limit is {max}"
+            ),
+            Error::Alloc {
+                type_name,
+                offset,
+                size,
+            } => write!(
+                f,
+                "could not allocate {size} bytes for `{type_name}` at offset {offset}"
+            ),
+            Error::Leftover {
+                type_name,
+                offset,
+                remaining,
+            } => write!(
+                f,
+                "{remaining} bytes of `{type_name}` were left over at offset {offset}"
+            ),
+            Error::Overrun {
+                type_name,
+                offset,
+                needed,
+                available,
+            } => write!(
+                f,
+                "`{type_name}` at offset {offset} overruns its section: needed {needed}, but only {available} left"
+            ),
+            Error::Fragmented {
+                type_name,
+                offset,
+                size,
+            } => write!(
+                f,
+                "the {size} bytes of `{type_name}` at offset {offset} span chunks that cannot be read without advancing"
+            ),
+            Error::Utf8 {
+                type_name,
+                offset,
+                valid_up_to,
+            } => write!(
+                f,
+                "`{type_name}` at offset {offset} is not valid UTF-8 after {valid_up_to} bytes"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+impl From<Infallible> for Error {
+    fn from(e: Infallible) -> Self {
+        match e {}
+    }
+}
+
+impl<Src, Dst> From<SizeError<Src, Dst>> for Error
+where
+    Src: Deref<Target = [u8]>,
+    Dst: KnownLayout + ?Sized,
+{
+    fn from(e: SizeError<Src, Dst>) -> Self {
+        Error::size::<Dst>(layout::min_size_of::<Dst>(), e.into_src().len())
+    }
+}
+
+impl<Src, Dst: ?Sized> From<AlignmentError<Src, Dst>> for Error {
+    fn from(_: AlignmentError<Src, Dst>) -> Self {
+        Error::alignment::<Dst>()
+    }
+}
+
+impl<Src, Dst: TryFromBytes + ?Sized> From<ValidityError<Src, Dst>> for Error {
+    fn from(_: ValidityError<Src, Dst>) -> Self {
+        Error::validity::<Dst>()
+    }
+}
+
+impl<A, S, V> From<ConvertError<A, S, V>> for Error
+where
+    A: Into<Error>,
+    S: Into<Error>,
+    V: Into<Error>,
+{
+    fn from(e: ConvertError<A, S, V>) -> Self {
+        match e {
+            ConvertError::Alignment(e) => e.into(),
+            ConvertError::Size(e) => e.into(),
+            ConvertError::Validity(e) => e.into(),
+        }
+    }
+}
@@ -0,0 +1,187 @@
+//! An iterator over length-delimited frames in a stream.
+
+use core::{marker::PhantomData, mem, ops::Deref};
+
+use bytes::{Bytes, BytesMut};
+use zerocopy::{FromBytes, Immutable, KnownLayout, Ref, Unaligned};
+
+use crate::{ByteSlice, Error, ZeroCopyBuf};
+
+/// An iterator over length-delimited frames, each starting with a header `H`.
+///
+/// Each frame is yielded as the header and the payload that follows it.
+/// Iteration stops when the buffer does not contain a complete frame,
+/// leaving any partial frame in the buffer. [`Frames::needed`] reports how many more bytes
+/// are required to complete it.
+///
+/// If a header describes an invalid frame, an [`Error`] is yielded and iteration stops.
+/// A length that is smaller than the header when it includes the header,
+/// or so large that the frame size overflows a `usize`, is an [`Error::Validity`].
+///
+/// ```
+/// use bytes::Bytes;
+/// use zerocopy::network_endian::U16;
+/// use zerocopy_buf::Frames;
+///
+/// let mut data = Bytes::from_static(b"\x00\x05hello\x00\x05world\x00\x03ab");
+/// let mut frames = Frames::new(&mut data, |len: &U16| len.get() as usize);
+///
+/// let (_, hello) = frames.next().unwrap().unwrap();
+/// assert_eq!(hello, b"hello"[..]);
+/// let (_, world) = frames.next().unwrap().unwrap();
+/// assert_eq!(world, b"world"[..]);
+///
+/// assert!(frames.next().is_none());
+/// assert_eq!(frames.needed(), Some(1));
+/// assert_eq!(data, b"\x00\x03ab"[..]);
+/// ```
+pub struct Frames<'a, B, H, F> {
+    buf: &'a mut B,
+    len: F,
+    length_includes_header: bool,
+    max_frame_size: Option<usize>,
+    offset: usize,
+    done: bool,
+    _header: PhantomData<fn() -> H>,
+}
+
+impl<'a, B, H, F> Frames<'a, B, H, F>
+where
+    B: Deref<Target = [u8]>,
+    H: FromBytes + KnownLayout + Immutable + Unaligned,
+    F: Fn(&H) -> usize,
+{
+    /// Split frames off the front of `buf`.
+    ///
+    /// `len` returns the length of the payload described by a header.
+    pub fn new(buf: &'a mut B, len: F) -> Self {
+        Self {
+            buf,
+            len,
+            length_includes_header: false,
+            max_frame_size: None,
+            offset: 0,
+            done: false,
+            _header: PhantomData,
+        }
+    }
+
+    /// Whether the length returned by the header includes the size of the header itself.
+    ///
+    /// Defaults to `false`.
+    pub fn length_includes_header(mut self, yes: bool) -> Self {
+        self.length_includes_header = yes;
+        self
+    }
+
+    /// The largest frame allowed, including the header.
+    ///
+    /// Larger frames produce an [`Error::TooLarge`] as soon as their header is available.
+    pub fn max_frame_size(mut self, max: usize) -> Self {
+        self.max_frame_size = Some(max);
+        self
+    }
+
+    /// The number of bytes consumed by the frames yielded so far.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The number of bytes still required to complete the partial frame in the buffer.
+    ///
+    /// Returns `None` if the buffer is empty, if it already contains a complete frame,
+    /// or if the next frame is invalid.
+    ///
+    /// If the header itself is incomplete, this is the number of bytes needed to complete the header.
+    pub fn needed(&self) -> Option<usize> {
+        let available = self.buf.len();
+        match self.frame_size() {
+            _ if available == 0 => None,
+            Ok(Some(size)) if size > available => Some(size - available),
+            Ok(Some(_)) | Err(_) => None,
+            Ok(None) => Some(mem::size_of::<H>() - available),
+        }
+    }
+
+    /// The size of the next frame, or `None` if the header is incomplete.
+    fn frame_size(&self) -> Result<Option<usize>, Error> {
+        let Ok((header, _)) = Ref::<_, H>::from_prefix(&**self.buf) else {
+            return Ok(None);
+        };
+        let len = (self.len)(&header);
+
+        let size = if self.length_includes_header {
+            if len < mem::size_of::<H>() {
+                return Err(Error::validity::<H>());
+            }
+            len
+        } else {
+            // a frame whose size does not fit in a usize can never be complete.
+            len.checked_add(mem::size_of::<H>())
+                .ok_or_else(Error::validity::<H>)?
+        };
+
+        match self.max_frame_size {
+            Some(max) if size > max => Err(Error::too_large::<H>(size, max)),
+            _ => Ok(Some(size)),
+        }
+    }
+
+    /// Get the size of the next complete frame, if there is one.
+    fn next_size(&mut self) -> Option<Result<usize, Error>> {
+        if self.done {
+            return None;
+        }
+        match self.frame_size() {
+            Ok(Some(size)) if size <= self.buf.len() => {
+                self.offset += size;
+                Some(Ok(size))
+            }
+            Ok(_) => None,
+            Err(e) => {
+                self.done = true;
+                Some(Err(e.at(self.offset)))
+            }
+        }
+    }
+}
+
+/// Split the header off a frame.
+fn split_header<H: KnownLayout + Immutable + Unaligned>(
+    mut frame: Bytes,
+) -> Result<(Ref<ByteSlice<Bytes>, H>, Bytes), Error> {
+    let header = frame.try_get::<H>()?;
+    Ok((header, frame))
+}
+
+impl<H, F> Iterator for Frames<'_, Bytes, H, F>
+where
+    H: FromBytes + KnownLayout + Immutable + Unaligned,
+    F: Fn(&H) -> usize,
+{
+    type Item = Result<(Ref<ByteSlice<Bytes>, H>, Bytes), Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let size = match self.next_size()? {
+            Ok(size) => size,
+            Err(e) => return Some(Err(e)),
+        };
+        Some(split_header(self.buf.split_to(size)))
+    }
+}
+
+impl<H, F> Iterator for Frames<'_, BytesMut, H, F>
+where
+    H: FromBytes + KnownLayout + Immutable + Unaligned,
+    F: Fn(&H) -> usize,
+{
+    type Item = Result<(Ref<ByteSlice<Bytes>, H>, Bytes), Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let size = match self.next_size()? {
+            Ok(size) => size,
+            Err(e) => return Some(Err(e)),
+        };
+        Some(split_header(self.buf.split_to(size).freeze()))
+    }
+}
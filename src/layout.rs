@@ -0,0 +1,14 @@
+//! Size calculations for [`KnownLayout`] types.
+
+use zerocopy::{KnownLayout, PointerMetadata};
+
+/// The size of a `T` with the given pointer metadata,
+/// or `None` if it does not fit in a `usize`.
+pub fn size_of<T: KnownLayout + ?Sized>(meta: T::PointerMetadata) -> Option<usize> {
+    T::size_for_metadata(meta)
+}
+
+/// The smallest possible size of a `T`.
+pub fn min_size_of<T: KnownLayout + ?Sized>() -> usize {
+    size_of::<T>(T::PointerMetadata::from_elem_count(0)).unwrap_or(usize::MAX)
+}
@@ -0,0 +1,76 @@
+//! Integer types that can be used as length fields.
+
+use zerocopy::{
+    byteorder::ByteOrder, FromBytes, Immutable, IntoBytes, KnownLayout, Unaligned, U16, U32, U64,
+};
+
+use crate::{U24, U48};
+
+/// An unsigned integer type that can be read as a length field, such as `u8` or [`U16`].
+pub trait Length: FromBytes + IntoBytes + KnownLayout + Immutable + Unaligned + Copy {
+    /// The largest length that can be represented, saturating at [`usize::MAX`].
+    const MAX: usize;
+
+    /// The length as a `usize`, saturating at [`usize::MAX`].
+    fn to_usize(self) -> usize;
+
+    /// Create the length from a `usize`, or [`None`] if it is larger than [`Length::MAX`].
+    fn from_usize(n: usize) -> Option<Self>;
+}
+
+/// `n` as a `usize`, saturating at [`usize::MAX`].
+const fn saturate(n: u64) -> usize {
+    if n as u128 > usize::MAX as u128 {
+        usize::MAX
+    } else {
+        n as usize
+    }
+}
+
+impl Length for u8 {
+    const MAX: usize = u8::MAX as usize;
+
+    fn to_usize(self) -> usize {
+        self.into()
+    }
+
+    fn from_usize(n: usize) -> Option<Self> {
+        n.try_into().ok()
+    }
+}
+
+macro_rules! impl_length {
+    ($($ty:ident($native:ident)),*) => {$(
+        impl<O: ByteOrder> Length for $ty<O> {
+            const MAX: usize = saturate($native::MAX as u64);
+
+            fn to_usize(self) -> usize {
+                usize::try_from(self.get()).unwrap_or(usize::MAX)
+            }
+
+            fn from_usize(n: usize) -> Option<Self> {
+                $native::try_from(n).ok().map($ty::new)
+            }
+        }
+    )*};
+}
+
+impl_length!(U16(u16), U32(u32), U64(u64));
+
+macro_rules! impl_length_checked {
+    ($($ty:ident($native:ident)),*) => {$(
+        impl<O: ByteOrder> Length for $ty<O> {
+            const MAX: usize = saturate($ty::<O>::MAX as u64);
+
+            fn to_usize(self) -> usize {
+                usize::try_from(self.get()).unwrap_or(usize::MAX)
+            }
+
+            fn from_usize(n: usize) -> Option<Self> {
+                $native::try_from(n).ok().and_then($ty::new)
+            }
+        }
+    )*};
+}
+
+impl_length_checked!(U24(u32), U48(u64));
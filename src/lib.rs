@@ -1,26 +1,88 @@
 //! Extensions for [`bytes::Buf`] with compatibility with [`zerocopy`].
 #![no_std]
 
-use bytes::{Buf, BufMut, Bytes, BytesMut};
+use alloc::{boxed::Box, collections::VecDeque, vec::Vec};
+use bytes::{
+    buf::{Chain, Take},
+    Buf, BufMut, Bytes, BytesMut,
+};
 use core::{
     mem,
     ops::{Deref, DerefMut},
 };
-use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout, Ref, SizeError, Unaligned};
+use zerocopy::{
+    byteorder::{BigEndian, ByteOrder, LittleEndian},
+    FromBytes, Immutable, IntoBytes, KnownLayout, Ref, TryFromBytes, Unaligned,
+};
 
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 mod buf_polyfill;
+mod byte_str;
+mod byteorder;
+mod cstr;
+mod endian;
+mod error;
+mod frames;
+mod layout;
+mod length;
 mod mu_polyfill;
+mod parsed;
+mod primitive;
+mod quic;
+mod read;
+mod records;
+mod rewind;
+mod slice;
+mod tlv;
+mod tracked;
+mod try_ref;
+mod varint;
+
+pub use byte_str::ByteStr;
+pub use byteorder::{I24, I48, U24, U48};
+pub use cstr::CStrBuf;
+pub use endian::{DynEndian, Endian};
+pub use error::Error;
+pub use frames::Frames;
+pub use length::Length;
+pub use parsed::Parsed;
+pub use primitive::Primitive;
+pub use quic::QuicVarInt;
+pub use records::Records;
+pub use rewind::Rewind;
+pub use slice::ZeroCopySlice;
+pub use tlv::Tlv;
+pub use tracked::{TraceEntry, Tracked};
+pub use try_ref::TryRef;
 
 /// A [`Buf`] that allows reading arbitrary [`zerocopy::FromBytes`] values from the buffer.
+///
+/// This is implemented for all the buffers of the `bytes` crate, and for [`Tracked`].
+/// Every method is provided, so another [`Buf`] only needs an empty `impl`.
 pub trait ZeroCopyReadBuf: Buf + Sized {
+    /// The offset of this buffer into the message, which is added to the offset of errors.
+    ///
+    /// This is 0 by default. [`Tracked`] returns the number of bytes it has consumed.
+    fn read_offset(&self) -> usize {
+        0
+    }
+
+    /// Called after a `T` of `size` bytes has been read, once the buffer has been advanced past it.
+    ///
+    /// This does nothing by default. [`Tracked`] records the read in its trace.
+    fn record_read<T: ?Sized>(&mut self, size: usize) {
+        let _ = size;
+    }
+
     /// Read a `T` from the [`Buf`].
     ///
     /// If [`Buf::remaining`] is greater than or equal to the size of `T`,
     /// then a T is returned and the buffer is advanced by the size of `T`.
     ///
-    /// If [`Buf::remaining`] is less than the size of `T`, A [`SizeError`] is returned.
+    /// If [`Buf::remaining`] is less than the size of `T`, an [`Error::Size`] is returned.
     ///
     /// This single method imitates all of the `Buf::get_...` methods.
     /// For example, [`Buf::get_u16`] could be written as:
@@ -31,10 +93,267 @@ pub trait ZeroCopyReadBuf: Buf + Sized {
     /// let x = data.try_read::<zerocopy::network_endian::U16>().unwrap();
     /// assert_eq!(x.get(), 0x0102);
     /// ```
-    fn try_read<T: FromBytes>(&mut self) -> Result<T, SizeError<(), T>>;
+    fn try_read<T: FromBytes>(&mut self) -> Result<T, Error> {
+        read::with_hooks::<_, T, _>(self, read::read)
+    }
+
+    /// Read a validated `T` from the [`Buf`].
+    ///
+    /// Like [`ZeroCopyReadBuf::try_read`], but supports any [`TryFromBytes`] type,
+    /// such as enums, `bool` or `NonZero` integers.
+    ///
+    /// If the bytes are not a valid `T`, an [`Error::Validity`] is returned
+    /// and the buffer is not advanced.
+    ///
+    /// The bytes of `T` are validated before advancing, so they must all be visible in the
+    /// [`Buf::chunk`], or with the `std` feature, in the chunks returned by `Buf::chunks_vectored`.
+    /// If they are not, an [`Error::Fragmented`] is returned and the buffer is not advanced.
+    ///
+    /// ```
+    /// use zerocopy_buf::ZeroCopyReadBuf;
+    ///
+    /// let mut data: &[u8] = &b"\x01\x02"[..];
+    /// assert!(data.try_read_checked::<bool>().unwrap());
+    /// assert!(data.try_read_checked::<bool>().is_err());
+    /// assert_eq!(data, b"\x02");
+    /// ```
+    fn try_read_checked<T: TryFromBytes>(&mut self) -> Result<T, Error> {
+        read::with_hooks::<_, T, _>(self, read::read_checked)
+    }
+
+    /// Read `count` values of `T` from the [`Buf`] into a [`Vec`].
+    ///
+    /// The [`Vec`] is allocated once, and the bytes are copied in bulk across chunks.
+    ///
+    /// If [`Buf::remaining`] is less than the size of `count` values of `T`,
+    /// or that size overflows a `usize`, an [`Error::Size`] is returned before anything is allocated.
+    /// If the allocation fails, an [`Error::Alloc`] is returned.
+    /// In both cases the buffer is not advanced.
+    ///
+    /// ```
+    /// use zerocopy_buf::ZeroCopyReadBuf;
+    /// use zerocopy::network_endian::U16;
+    ///
+    /// let mut data: &[u8] = &b"\x00\x01\x00\x02\x00\x03"[..];
+    /// let x = data.try_read_elems::<U16>(3).unwrap();
+    /// assert_eq!(x, [U16::new(1), U16::new(2), U16::new(3)]);
+    /// ```
+    fn try_read_elems<T: FromBytes>(&mut self, count: usize) -> Result<Vec<T>, Error> {
+        read::with_hooks::<_, [T], _>(self, |this| read::read_elems(this, count))
+    }
+
+    /// Read `count` values of `T` from the [`Buf`] into a boxed slice.
+    ///
+    /// Like [`ZeroCopyReadBuf::try_read_elems`].
+    fn try_read_boxed_slice<T: FromBytes>(&mut self, count: usize) -> Result<Box<[T]>, Error> {
+        self.try_read_elems(count).map(Vec::into_boxed_slice)
+    }
+
+    /// Read from the [`Buf`] into an existing `T`, overwriting all of its bytes.
+    ///
+    /// Unlike [`ZeroCopyReadBuf::try_read`], no temporary `T` is created on the stack,
+    /// so this is suitable for very large values.
+    ///
+    /// If [`Buf::remaining`] is less than the size of `t`, an [`Error::Size`] is returned,
+    /// and neither the buffer nor `t` is modified.
+    ///
+    /// ```
+    /// use zerocopy_buf::ZeroCopyReadBuf;
+    ///
+    /// let mut state = [0u8; 4];
+    /// let mut data: &[u8] = &b"\x01\x02\x03\x04\x05"[..];
+    /// data.try_read_into(&mut state).unwrap();
+    /// assert_eq!(state, [1, 2, 3, 4]);
+    /// assert!(data.try_read_into(&mut state).is_err());
+    /// ```
+    fn try_read_into<T: FromBytes + ?Sized>(&mut self, t: &mut T) -> Result<(), Error> {
+        read::with_hooks::<_, T, _>(self, |this| read::read_into(this, t))
+    }
+
+    /// Read a DST `T` with `count` elements from the [`Buf`] into a [`Box`].
+    ///
+    /// If [`Buf::remaining`] is less than the size of `T` with `count` elements,
+    /// or that size overflows a `usize`, an [`Error::Size`] is returned before anything is allocated.
+    /// If the allocation fails, an [`Error::Alloc`] is returned.
+    /// In both cases the buffer is not advanced.
+    ///
+    /// ```
+    /// use bytes::Buf;
+    /// use zerocopy_buf::ZeroCopyReadBuf;
+    /// use zerocopy::{FromBytes, KnownLayout, Immutable};
+    ///
+    /// #[derive(FromBytes, KnownLayout, Immutable)]
+    /// #[repr(C)]
+    /// struct Packet {
+    ///     len: u8,
+    ///     body: [u8],
+    /// }
+    ///
+    /// let mut data = (&b"\x05hel"[..]).chain(&b"lo"[..]);
+    /// let packet = data.try_read_boxed::<Packet>(5).unwrap();
+    /// assert_eq!(packet.body, b"hello"[..]);
+    /// ```
+    fn try_read_boxed<T: FromBytes + KnownLayout<PointerMetadata = usize> + ?Sized>(
+        &mut self,
+        count: usize,
+    ) -> Result<Box<T>, Error> {
+        read::with_hooks::<_, T, _>(self, |this| read::read_boxed(this, count))
+    }
+
+    /// Read a `T` from the [`Buf`] without advancing it.
+    ///
+    /// The bytes are copied across as many chunks as needed. Without the `std` feature,
+    /// only the current [`Buf::chunk`] can be seen without advancing.
+    /// With the `std` feature, all the chunks returned by `Buf::chunks_vectored` can be seen.
+    ///
+    /// If fewer bytes than the size of `T` are visible, an [`Error::Size`] is returned
+    /// with the number of visible bytes as `available`.
+    ///
+    /// ```
+    /// use zerocopy_buf::ZeroCopyReadBuf;
+    ///
+    /// let data: &[u8] = &b"\x01\x02"[..];
+    /// let x = data.try_peek_read::<zerocopy::network_endian::U16>().unwrap();
+    /// assert_eq!(x.get(), 0x0102);
+    /// assert_eq!(data.len(), 2);
+    /// ```
+    fn try_peek_read<T: FromBytes>(&self) -> Result<T, Error> {
+        self.try_peek_read_at::<T>(0)
+    }
+
+    /// Read a `T` from `offset` bytes into the [`Buf`] without advancing it.
+    ///
+    /// Like [`ZeroCopyReadBuf::try_peek_read`]. The error offset is the position of the `T`.
+    ///
+    /// ```
+    /// use zerocopy_buf::ZeroCopyReadBuf;
+    ///
+    /// let data: &[u8] = &b"\x02ab\x00\x03"[..];
+    /// let x = data.try_peek_read_at::<zerocopy::network_endian::U16>(3).unwrap();
+    /// assert_eq!(x.get(), 3);
+    /// ```
+    fn try_peek_read_at<T: FromBytes>(&self, offset: usize) -> Result<T, Error> {
+        read::peek_read_at(self, offset).map_err(|e| e.at(self.read_offset()))
+    }
+
+    /// Read a native `T` encoded in the byte order `O` from the [`Buf`].
+    ///
+    /// Like [`ZeroCopyReadBuf::try_read`], but converts from the matching
+    /// [`zerocopy::byteorder`] type. Unlike [`Buf::get_u32`] and friends,
+    /// this returns an [`Error::Size`] instead of panicking if the buffer is too short.
+    ///
+    /// ```
+    /// use zerocopy_buf::ZeroCopyReadBuf;
+    /// use zerocopy::byteorder::NetworkEndian;
+    ///
+    /// let mut data: &[u8] = &b"\x01\x02"[..];
+    /// assert_eq!(data.try_read_endian::<NetworkEndian, u16>().unwrap(), 0x0102);
+    /// assert!(data.try_read_endian::<NetworkEndian, u16>().is_err());
+    /// ```
+    fn try_read_endian<O: ByteOrder, T: Primitive>(&mut self) -> Result<T, Error> {
+        self.try_read::<T::Wrapper<O>>().map(T::from_wrapper)
+    }
+
+    /// Read a big-endian `T` from the [`Buf`].
+    ///
+    /// See [`ZeroCopyReadBuf::try_read_endian`].
+    ///
+    /// ```
+    /// use zerocopy_buf::ZeroCopyReadBuf;
+    ///
+    /// let mut data: &[u8] = &b"\x00\x00\x01\x02"[..];
+    /// assert_eq!(data.try_read_be::<u32>().unwrap(), 0x0102);
+    /// ```
+    fn try_read_be<T: Primitive>(&mut self) -> Result<T, Error> {
+        self.try_read_endian::<BigEndian, T>()
+    }
+
+    /// Read a little-endian `T` from the [`Buf`].
+    ///
+    /// See [`ZeroCopyReadBuf::try_read_endian`].
+    ///
+    /// ```
+    /// use zerocopy_buf::ZeroCopyReadBuf;
+    ///
+    /// let mut data: &[u8] = &b"\x02\x01\x00\x00"[..];
+    /// assert_eq!(data.try_read_le::<i32>().unwrap(), 0x0102);
+    /// ```
+    fn try_read_le<T: Primitive>(&mut self) -> Result<T, Error> {
+        self.try_read_endian::<LittleEndian, T>()
+    }
+
+    /// Read a `T` in the byte order `endian` that was chosen at runtime.
+    ///
+    /// See [`ZeroCopyReadBuf::try_read_endian`].
+    ///
+    /// ```
+    /// use zerocopy_buf::{Endian, ZeroCopyReadBuf};
+    ///
+    /// let mut data: &[u8] = &b"\x02\x01"[..];
+    /// assert_eq!(data.try_read_dyn::<u16>(Endian::Little).unwrap(), 0x0102);
+    /// ```
+    fn try_read_dyn<T: Primitive>(&mut self, endian: Endian) -> Result<T, Error> {
+        self.try_read::<DynEndian<T>>().map(|t| t.get(endian))
+    }
+
+    /// Read an unsigned LEB128 varint from the [`Buf`], as used by protobuf and WebAssembly.
+    ///
+    /// If the varint is not terminated before the end of the buffer, an [`Error::Size`] is returned.
+    /// If the encoding is longer than necessary, or the value does not fit in a `T`,
+    /// an [`Error::Validity`] is returned.
+    ///
+    /// The buffer is not advanced on error, unless the varint spans more than one [`Buf::chunk`]
+    /// and cannot all be seen without advancing, as with [`ZeroCopyReadBuf::try_read_checked`].
+    /// In that case the bytes read so far have already been consumed.
+    ///
+    /// ```
+    /// use zerocopy_buf::ZeroCopyReadBuf;
+    ///
+    /// let mut data: &[u8] = &b"\xac\x02\x80\x80\x04"[..];
+    /// assert_eq!(data.try_read_uvarint::<u16>().unwrap(), 300);
+    /// assert!(data.try_read_uvarint::<u16>().is_err());
+    /// assert_eq!(data.try_read_uvarint::<u32>().unwrap(), 1 << 16);
+    /// ```
+    fn try_read_uvarint<T: TryFrom<u64>>(&mut self) -> Result<T, Error> {
+        read::with_hooks::<_, T, _>(self, |this| varint::read(this, |n| T::try_from(n).ok()))
+    }
+
+    /// Read a zig-zag encoded signed LEB128 varint from the [`Buf`], as used by protobuf's `sint` types.
+    ///
+    /// See [`ZeroCopyReadBuf::try_read_uvarint`].
+    ///
+    /// ```
+    /// use zerocopy_buf::ZeroCopyReadBuf;
+    ///
+    /// let mut data: &[u8] = &b"\x03\x04"[..];
+    /// assert_eq!(data.try_read_svarint::<i32>().unwrap(), -2);
+    /// assert_eq!(data.try_read_svarint::<i32>().unwrap(), 2);
+    /// ```
+    fn try_read_svarint<T: TryFrom<i64>>(&mut self) -> Result<T, Error> {
+        read::with_hooks::<_, T, _>(self, |this| {
+            varint::read(this, |n| T::try_from(varint::zigzag_decode(n)).ok())
+        })
+    }
+
+    /// Read a [`QuicVarInt`] from the [`Buf`].
+    ///
+    /// If [`Buf::remaining`] is less than the length given by the first byte,
+    /// an [`Error::Size`] is returned and the buffer is not advanced.
+    ///
+    /// ```
+    /// use zerocopy_buf::ZeroCopyReadBuf;
+    ///
+    /// let mut data: &[u8] = &b"\x7b\xbd\x25"[..];
+    /// assert_eq!(data.try_read_quic_varint().unwrap().get(), 15293);
+    /// assert_eq!(data.try_read_quic_varint().unwrap().get(), 37);
+    /// ```
+    fn try_read_quic_varint(&mut self) -> Result<QuicVarInt, Error> {
+        read::with_hooks::<_, QuicVarInt, _>(self, read::read_quic_varint)
+    }
 }
 
-type Res<Buf, T> = Result<Ref<Buf, T>, SizeError<Buf, T>>;
+type Res<Buf, T> = Result<Ref<Buf, T>, Error>;
+type TryRes<Buf, T> = Result<TryRef<Buf, T>, Error>;
 
 /// A [`Buf`] that allows getting arbitrary values from the buffer.
 pub trait ZeroCopyBuf: Buf {
@@ -46,7 +365,7 @@ pub trait ZeroCopyBuf: Buf {
     /// If [`Buf::remaining`] is greater than or equal to the size of `T`,
     /// then a [`Ref<Self::Buf, T>`] is returned and the buffer is advanced by the size of `T`.
     ///
-    /// If [`Buf::remaining`] is less than the size of `T`, A [`SizeError`] is returned.
+    /// If [`Buf::remaining`] is less than the size of `T`, an [`Error::Size`] is returned.
     ///
     /// This single method imitates all of the `Buf::get_...` methods.
     /// For example, [`Buf::get_u16`] could be written as:
@@ -64,7 +383,7 @@ pub trait ZeroCopyBuf: Buf {
     /// If [`Buf::remaining`] is greater than or equal to the size of `T` with `count` elements,
     /// then a [`Ref<Self::Buf, T>`] is returned and the buffer is advanced by the size of `T`.
     ///
-    /// If [`Buf::remaining`] is less, A [`SizeError`] is returned.
+    /// If [`Buf::remaining`] is less, an [`Error::Size`] is returned.
     ///
     /// ```
     /// use zerocopy_buf::ZeroCopyBuf;
@@ -85,7 +404,7 @@ pub trait ZeroCopyBuf: Buf {
     /// If [`Buf::remaining`] is greater than or equal to the size of `T`,
     /// then a [`Ref<Self::Buf, T>`] is returned and the buffer is **NOT** advanced by the size of `T`.
     ///
-    /// If [`Buf::remaining`] is less than the size of `T`, A [`SizeError`] is returned.
+    /// If [`Buf::remaining`] is less than the size of `T`, an [`Error::Size`] is returned.
     ///
     /// ```
     /// use zerocopy_buf::ZeroCopyBuf;
@@ -112,146 +431,1191 @@ pub trait ZeroCopyBuf: Buf {
     /// let packet = data.try_get_elems::<Packet>(payload_len as usize).unwrap();
     /// assert_eq!(packet.body, b"hello world"[..]);
     /// ```
-    fn try_peek<T: KnownLayout + Immutable + Unaligned>(&mut self) -> Res<&[u8], T>;
+    fn try_peek<T: KnownLayout + Immutable + Unaligned>(&self) -> Res<&[u8], T>;
 
     /// Get a ref to a DST `T` from the [`Buf`].
     ///
     /// If [`Buf::remaining`] is greater than or equal to the size of `T` with `count` elements,
     /// then a [`Ref<Self::Buf, T>`] is returned and the buffer is **NOT** advanced by the size of `T`.
     ///
-    /// If [`Buf::remaining`] is less, A [`SizeError`] is returned.
+    /// If [`Buf::remaining`] is less, an [`Error::Size`] is returned.
     fn try_peek_elems<T: KnownLayout<PointerMetadata = usize> + Immutable + Unaligned + ?Sized>(
-        &mut self,
+        &self,
         count: usize,
     ) -> Res<&[u8], T>;
-}
 
-/// A [`BufMut`] that uses [`zerocopy::IntoBytes`] to encode
-pub trait ZeroCopyBufMut: BufMut {
-    /// Write a `T` to the [`BufMut`].
+    /// Get a mutable ref to a `T` from the [`Buf`] without advancing it.
+    ///
+    /// Like [`ZeroCopyBuf::try_peek`], but the bytes can be modified in place,
+    /// for example to patch a header before deciding whether to consume it.
     ///
-    /// This single method imitates all of the `BufMut::put_...` methods.
-    /// For example, [`BufMut::put_u16`] could be written as:
     /// ```
-    /// use zerocopy_buf::ZeroCopyBufMut;
+    /// use zerocopy_buf::ZeroCopyBuf;
     ///
-    /// let mut data = bytes::BytesMut::new();
-    /// data.write(zerocopy::network_endian::U16::new(0x0102));
-    /// assert_eq!(&data, &b"\x01\x02"[..]);
+    /// let mut data = bytes::BytesMut::from(&b"\x00\x40payload"[..]);
+    /// data.try_peek_mut::<zerocopy::network_endian::U16>().unwrap().set(7);
+    ///
+    /// let len = data.try_get::<zerocopy::network_endian::U16>().unwrap();
+    /// assert_eq!(len.get(), 7);
     /// ```
-    fn write<T: IntoBytes + Immutable>(&mut self, t: &T);
-}
-
-impl<B: Buf> ZeroCopyReadBuf for B {
-    fn try_read<T: FromBytes>(&mut self) -> Result<T, SizeError<(), T>> {
-        let mut t = mem::MaybeUninit::<T>::uninit();
-        let bytes = buf_polyfill::copy_to_uninit_slice(self, mu_polyfill::as_bytes_mut(&mut t))
-            .unwrap_or_default();
-
-        T::read_from_bytes(bytes).map_err(|e| e.map_src(|_| ()))
-    }
-}
-
-impl ZeroCopyBuf for Bytes {
-    type Buf = ByteSlice<Bytes>;
-
-    fn try_get<T: KnownLayout + Immutable + Unaligned>(&mut self) -> Res<Self::Buf, T> {
-        let (a, b) = Ref::from_prefix(ByteSlice(mem::take(self)))
-            .map_err(SizeError::from)
-            .map_err(|e| e.map_src(|s| ByteSlice(mem::replace(self, s.0))))?;
-        *self = b.0;
+    fn try_peek_mut<T: KnownLayout + Immutable + Unaligned>(&mut self) -> Res<&mut [u8], T>
+    where
+        Self: DerefMut<Target = [u8]>,
+    {
+        let (a, _) = Ref::from_prefix(&mut **self)?;
         Ok(a)
     }
 
-    fn try_get_elems<T: KnownLayout<PointerMetadata = usize> + Immutable + Unaligned + ?Sized>(
+    /// Get a mutable ref to a DST `T` from the [`Buf`] without advancing it.
+    ///
+    /// Like [`ZeroCopyBuf::try_peek_elems`], but the bytes can be modified in place.
+    fn try_peek_elems_mut<
+        T: KnownLayout<PointerMetadata = usize> + Immutable + Unaligned + ?Sized,
+    >(
         &mut self,
         count: usize,
-    ) -> Res<Self::Buf, T> {
-        let (a, b) = Ref::from_prefix_with_elems(ByteSlice(mem::take(self)), count)
-            .map_err(SizeError::from)
-            .map_err(|e| e.map_src(|s| ByteSlice(mem::replace(self, s.0))))?;
-        *self = b.0;
+    ) -> Res<&mut [u8], T>
+    where
+        Self: DerefMut<Target = [u8]>,
+    {
+        let (a, _) = Ref::from_prefix_with_elems(&mut **self, count)
+            .map_err(|e| Error::from(e).with_elems::<T>(count))?;
         Ok(a)
     }
 
-    fn try_peek<T: KnownLayout + Immutable + Unaligned>(&mut self) -> Res<&[u8], T> {
-        let (a, _) = Ref::from_prefix(&**self).map_err(SizeError::from)?;
-        Ok(a)
-    }
+    /// Get a ref to a DST `T` from the [`Buf`], with the number of elements given by a header `H`.
+    ///
+    /// The header is peeked from the start of the buffer, and `len` returns the number of
+    /// trailing **elements** of `T` (not bytes). The header is expected to be the prefix of `T`.
+    ///
+    /// If [`Buf::remaining`] is less than either the header or the full `T`, or the size of `T`
+    /// overflows a `usize`, an [`Error::Size`] is returned and the buffer is not advanced.
+    ///
+    /// ```
+    /// use zerocopy_buf::ZeroCopyBuf;
+    /// use zerocopy::{FromBytes, KnownLayout, Immutable, Unaligned};
+    ///
+    /// #[derive(FromBytes, KnownLayout, Immutable, Unaligned)]
+    /// #[repr(C)]
+    /// struct PacketHeader {
+    ///     len: zerocopy::network_endian::U32,
+    /// }
+    ///
+    /// #[derive(FromBytes, KnownLayout, Immutable, Unaligned)]
+    /// #[repr(C)]
+    /// struct Packet {
+    ///     header: PacketHeader,
+    ///     body: [u8],
+    /// }
+    ///
+    /// let mut data: &[u8] = &b"\x00\x00\x00\x0bhello world"[..];
+    /// let packet = data
+    ///     .try_get_with_len::<PacketHeader, Packet>(|h| h.len.get() as usize)
+    ///     .unwrap();
+    /// assert_eq!(packet.body, b"hello world"[..]);
+    /// ```
+    fn try_get_with_len<H, T>(&mut self, len: impl FnOnce(&H) -> usize) -> Res<Self::Buf, T>
+    where
+        H: FromBytes + KnownLayout + Immutable + Unaligned,
+        T: KnownLayout<PointerMetadata = usize> + Immutable + Unaligned + ?Sized,
+    {
+        let count = len(&*self.try_peek::<H>()?);
 
-    fn try_peek_elems<T: KnownLayout<PointerMetadata = usize> + Immutable + Unaligned + ?Sized>(
-        &mut self,
-        count: usize,
-    ) -> Res<&[u8], T> {
-        let (a, _) = Ref::from_prefix_with_elems(&**self, count).map_err(SizeError::from)?;
-        Ok(a)
+        let available = self.remaining();
+        match layout::size_of::<T>(count) {
+            Some(needed) if needed <= available => self.try_get_elems::<T>(count),
+            needed => Err(Error::size::<T>(needed.unwrap_or(usize::MAX), available)),
+        }
     }
-}
 
-impl ZeroCopyBuf for BytesMut {
-    type Buf = ByteSlice<BytesMut>;
+    /// Get a length-prefixed byte string from the [`Buf`], as a buffer of the same kind.
+    ///
+    /// The length prefix `L` is any [`Length`], such as `u8` or [`zerocopy::network_endian::U16`],
+    /// and counts the bytes that follow it.
+    ///
+    /// If [`Buf::remaining`] is less than the prefix and the bytes it counts,
+    /// an [`Error::Size`] is returned and the buffer is not advanced.
+    ///
+    /// ```
+    /// use zerocopy_buf::ZeroCopyBuf;
+    /// use zerocopy::network_endian::U16;
+    ///
+    /// let mut data = bytes::Bytes::from_static(b"\x00\x05hello\x03abc");
+    /// assert_eq!(data.try_get_prefixed::<U16>().unwrap(), b"hello"[..]);
+    /// assert_eq!(data.try_get_prefixed::<u8>().unwrap(), b"abc"[..]);
+    /// assert!(data.is_empty());
+    /// ```
+    fn try_get_prefixed<L: Length>(&mut self) -> Result<Self, Error>
+    where
+        Self: Sized,
+    {
+        let len = self.try_peek::<L>()?.to_usize();
 
-    fn try_get<T: KnownLayout + Immutable + Unaligned>(&mut self) -> Res<Self::Buf, T> {
-        let (a, b) = Ref::from_prefix(ByteSlice(mem::take(self)))
-            .map_err(SizeError::from)
-            .map_err(|e| e.map_src(|s| ByteSlice(mem::replace(self, s.0))))?;
-        *self = b.0;
-        Ok(a)
+        let available = self.remaining();
+        match mem::size_of::<L>().checked_add(len) {
+            Some(needed) if needed <= available => {
+                self.try_get::<L>()?;
+                self.try_split_section(len)
+            }
+            needed => Err(Error::size::<[u8]>(needed.unwrap_or(usize::MAX), available)),
+        }
     }
 
-    fn try_get_elems<T: KnownLayout<PointerMetadata = usize> + Immutable + Unaligned + ?Sized>(
+    /// Get a ref to a length-prefixed slice of `T` records from the [`Buf`].
+    ///
+    /// Like [`ZeroCopyBuf::try_get_prefixed`], the prefix `L` counts **bytes**, not records,
+    /// as in TLS vectors. If the length is not a multiple of the size of `T`,
+    /// an [`Error::Leftover`] is returned and the buffer is not advanced.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` is zero-sized.
+    ///
+    /// ```
+    /// use zerocopy_buf::ZeroCopyBuf;
+    /// use zerocopy::network_endian::U16;
+    ///
+    /// let mut data: &[u8] = &b"\x00\x04\x13\x01\x13\x02"[..];
+    /// let suites = data.try_get_prefixed_elems::<U16, U16>().unwrap();
+    /// assert_eq!(suites.len(), 2);
+    /// assert_eq!(suites[1].get(), 0x1302);
+    /// ```
+    fn try_get_prefixed_elems<L: Length, T: KnownLayout + Immutable + Unaligned>(
         &mut self,
-        count: usize,
-    ) -> Res<Self::Buf, T> {
-        let (a, b) = Ref::from_prefix_with_elems(ByteSlice(mem::take(self)), count)
-            .map_err(SizeError::from)
-            .map_err(|e| e.map_src(|s| ByteSlice(mem::replace(self, s.0))))?;
-        *self = b.0;
-        Ok(a)
-    }
-
-    fn try_peek<T: KnownLayout + Immutable + Unaligned>(&mut self) -> Res<&[u8], T> {
-        let (a, _) = Ref::from_prefix(&**self).map_err(SizeError::from)?;
-        Ok(a)
-    }
+    ) -> Res<Self::Buf, [T]> {
+        assert!(
+            mem::size_of::<T>() != 0,
+            "zero-sized records are not supported"
+        );
+        let len = self.try_peek::<L>()?.to_usize();
 
-    fn try_peek_elems<T: KnownLayout<PointerMetadata = usize> + Immutable + Unaligned + ?Sized>(
-        &mut self,
-        count: usize,
-    ) -> Res<&[u8], T> {
-        let (a, _) = Ref::from_prefix_with_elems(&**self, count).map_err(SizeError::from)?;
-        Ok(a)
-    }
-}
+        let available = self.remaining();
+        let header = mem::size_of::<L>();
+        match header.checked_add(len) {
+            Some(needed) if needed <= available => {}
+            needed => return Err(Error::size::<[T]>(needed.unwrap_or(usize::MAX), available)),
+        }
 
-impl ZeroCopyBuf for &[u8] {
-    type Buf = Self;
+        let rem = len % mem::size_of::<T>();
+        if rem != 0 {
+            return Err(Error::leftover::<[T]>(rem).at(header + len - rem));
+        }
 
-    fn try_get<T: KnownLayout + Immutable + Unaligned>(&mut self) -> Res<Self::Buf, T> {
-        let (a, b) = Ref::from_prefix(*self).map_err(SizeError::from)?;
-        *self = b;
-        Ok(a)
+        self.try_get::<L>()?;
+        self.try_get_elems::<[T]>(len / mem::size_of::<T>())
     }
 
-    fn try_get_elems<T: KnownLayout<PointerMetadata = usize> + Immutable + Unaligned + ?Sized>(
+    /// Get a ref to a validated `T` from the [`Buf`].
+    ///
+    /// Like [`ZeroCopyBuf::try_get`], but supports any [`TryFromBytes`] type,
+    /// such as enums, `bool` or `NonZero` integers.
+    ///
+    /// If the bytes are not a valid `T`, an [`Error::Validity`] is returned
+    /// and the buffer is not advanced.
+    ///
+    /// ```
+    /// use zerocopy_buf::ZeroCopyBuf;
+    /// use zerocopy::{Immutable, KnownLayout, TryFromBytes, Unaligned};
+    ///
+    /// #[derive(TryFromBytes, KnownLayout, Immutable, Unaligned, Debug, PartialEq)]
+    /// #[repr(u8)]
+    /// enum Opcode {
+    ///     Request = 1,
+    ///     Reply = 2,
+    /// }
+    ///
+    /// let mut data: &[u8] = &b"\x02\x03"[..];
+    /// assert_eq!(*data.try_get_checked::<Opcode>().unwrap(), Opcode::Reply);
+    /// assert!(data.try_get_checked::<Opcode>().is_err());
+    /// assert_eq!(data, b"\x03");
+    /// ```
+    fn try_get_checked<T: TryFromBytes + KnownLayout + Immutable + Unaligned>(
+        &mut self,
+    ) -> TryRes<Self::Buf, T>;
+
+    /// Get a ref to a validated DST `T` from the [`Buf`].
+    ///
+    /// Like [`ZeroCopyBuf::try_get_elems`], but supports any [`TryFromBytes`] type.
+    ///
+    /// If the bytes are not a valid `T`, an [`Error::Validity`] is returned
+    /// and the buffer is not advanced.
+    fn try_get_elems_checked<
+        T: TryFromBytes + KnownLayout<PointerMetadata = usize> + Immutable + Unaligned + ?Sized,
+    >(
         &mut self,
         count: usize,
-    ) -> Res<Self::Buf, T> {
-        let (a, b) = Ref::from_prefix_with_elems(*self, count).map_err(SizeError::from)?;
-        *self = b;
-        Ok(a)
-    }
+    ) -> TryRes<Self::Buf, T>;
 
-    fn try_peek<T: KnownLayout + Immutable + Unaligned>(&mut self) -> Res<&[u8], T> {
-        let (a, _) = Ref::from_prefix(*self).map_err(SizeError::from)?;
-        Ok(a)
+    /// Get a ref to a validated `T` from the [`Buf`] without advancing it.
+    ///
+    /// Like [`ZeroCopyBuf::try_peek`], but supports any [`TryFromBytes`] type.
+    fn try_peek_checked<T: TryFromBytes + KnownLayout + Immutable + Unaligned>(
+        &self,
+    ) -> TryRes<&[u8], T>;
+
+    /// Get a ref to a validated DST `T` from the [`Buf`] without advancing it.
+    ///
+    /// Like [`ZeroCopyBuf::try_peek_elems`], but supports any [`TryFromBytes`] type.
+    fn try_peek_elems_checked<
+        T: TryFromBytes + KnownLayout<PointerMetadata = usize> + Immutable + Unaligned + ?Sized,
+    >(
+        &self,
+        count: usize,
+    ) -> TryRes<&[u8], T>;
+
+    /// Get a ref to an aligned `T` from the [`Buf`].
+    ///
+    /// Like [`ZeroCopyBuf::try_get`], but `T` does not need to be [`Unaligned`].
+    ///
+    /// If the current chunk is not suitably aligned for `T`,
+    /// an [`Error::Alignment`] is returned and the buffer is not advanced.
+    ///
+    /// ```
+    /// use zerocopy_buf::{Error, ZeroCopyBuf};
+    ///
+    /// #[repr(C, align(4))]
+    /// struct Aligned([u8; 8]);
+    /// static DATA: Aligned = Aligned(*b"\x01\x00\x00\x00\x02\x00\x00\x00");
+    ///
+    /// let mut data: &[u8] = &DATA.0[..];
+    /// assert_eq!(*data.try_get_aligned::<u32>().unwrap(), u32::from_ne_bytes(*b"\x01\x00\x00\x00"));
+    ///
+    /// let mut data: &[u8] = &DATA.0[1..];
+    /// assert!(matches!(data.try_get_aligned::<u32>(), Err(Error::Alignment { .. })));
+    /// assert_eq!(data.len(), 7);
+    /// ```
+    fn try_get_aligned<T: KnownLayout + Immutable>(&mut self) -> Res<Self::Buf, T>;
+
+    /// Get a ref to an aligned DST `T` from the [`Buf`].
+    ///
+    /// Like [`ZeroCopyBuf::try_get_elems`], but `T` does not need to be [`Unaligned`].
+    ///
+    /// If the current chunk is not suitably aligned for `T`,
+    /// an [`Error::Alignment`] is returned and the buffer is not advanced.
+    fn try_get_elems_aligned<T: KnownLayout<PointerMetadata = usize> + Immutable + ?Sized>(
+        &mut self,
+        count: usize,
+    ) -> Res<Self::Buf, T>;
+
+    /// Get a ref to an aligned `T` from the [`Buf`] without advancing it.
+    ///
+    /// Like [`ZeroCopyBuf::try_peek`], but `T` does not need to be [`Unaligned`].
+    fn try_peek_aligned<T: KnownLayout + Immutable>(&self) -> Res<&[u8], T>;
+
+    /// Get a ref to an aligned DST `T` from the [`Buf`] without advancing it.
+    ///
+    /// Like [`ZeroCopyBuf::try_peek_elems`], but `T` does not need to be [`Unaligned`].
+    fn try_peek_elems_aligned<T: KnownLayout<PointerMetadata = usize> + Immutable + ?Sized>(
+        &self,
+        count: usize,
+    ) -> Res<&[u8], T>;
+
+    /// Get a ref to a `T` from the end of the [`Buf`].
+    ///
+    /// If [`Buf::remaining`] is greater than or equal to the size of `T`,
+    /// then a [`Ref<Self::Buf, T>`] is returned and the buffer is truncated by the size of `T`.
+    ///
+    /// If [`Buf::remaining`] is less than the size of `T`, an [`Error::Size`] is returned.
+    ///
+    /// This is useful for formats that put a trailer at the end, such as a checksum.
+    /// ```
+    /// use zerocopy_buf::ZeroCopyBuf;
+    ///
+    /// let mut data: &[u8] = &b"hello\x01\x02"[..];
+    /// let crc = data.try_get_suffix::<zerocopy::network_endian::U16>().unwrap();
+    /// assert_eq!(crc.get(), 0x0102);
+    /// assert_eq!(data, b"hello");
+    /// ```
+    fn try_get_suffix<T: KnownLayout + Immutable + Unaligned>(&mut self) -> Res<Self::Buf, T>;
+
+    /// Get a ref to a DST `T` from the end of the [`Buf`].
+    ///
+    /// If [`Buf::remaining`] is greater than or equal to the size of `T` with `count` elements,
+    /// then a [`Ref<Self::Buf, T>`] is returned and the buffer is truncated by the size of `T`.
+    ///
+    /// If [`Buf::remaining`] is less, an [`Error::Size`] is returned.
+    fn try_get_suffix_elems<
+        T: KnownLayout<PointerMetadata = usize> + Immutable + Unaligned + ?Sized,
+    >(
+        &mut self,
+        count: usize,
+    ) -> Res<Self::Buf, T>;
+
+    /// Get a ref to a `T` from the end of the [`Buf`].
+    ///
+    /// If [`Buf::remaining`] is greater than or equal to the size of `T`,
+    /// then a [`Ref<Self::Buf, T>`] is returned and the buffer is **NOT** truncated by the size of `T`.
+    ///
+    /// If [`Buf::remaining`] is less than the size of `T`, an [`Error::Size`] is returned.
+    fn try_peek_suffix<T: KnownLayout + Immutable + Unaligned>(&self) -> Res<&[u8], T>;
+
+    /// Split off the next `len` bytes as a buffer of the same kind.
+    ///
+    /// If [`Buf::remaining`] is greater than or equal to `len`,
+    /// then the first `len` bytes are returned and the buffer is advanced by `len`.
+    ///
+    /// If [`Buf::remaining`] is less than `len`, an [`Error::Size`] is returned.
+    ///
+    /// ```
+    /// use zerocopy_buf::ZeroCopyBuf;
+    ///
+    /// let mut data = bytes::Bytes::from_static(b"\x01\x02\x03");
+    /// let section = data.try_split_section(2).unwrap();
+    /// assert_eq!(section, b"\x01\x02"[..]);
+    /// assert_eq!(data, b"\x03"[..]);
+    /// ```
+    fn try_split_section(&mut self, len: usize) -> Result<Self, Error>
+    where
+        Self: Sized;
+
+    /// Parse the next `len` bytes as a section with `f`.
+    ///
+    /// The section is split off with [`ZeroCopyBuf::try_split_section`] before calling `f`,
+    /// so `f` cannot read past the end of it. An [`Error::Size`] returned by `f` becomes an
+    /// [`Error::Overrun`], so that [`ZeroCopyBuf::parse_streaming`] does not wait for more bytes.
+    /// If `f` does not consume the whole section, an [`Error::Leftover`] is returned.
+    ///
+    /// The buffer is advanced past the section even if `f` fails.
+    /// Use [`ZeroCopyBuf::transaction`] to restore it instead.
+    ///
+    /// ```
+    /// use zerocopy_buf::{Error, ZeroCopyBuf};
+    /// use zerocopy::network_endian::U16;
+    ///
+    /// let mut data: &[u8] = &b"\x00\x01\x00\x02\xff"[..];
+    /// let (a, b) = data
+    ///     .with_section(4, |s| Ok((s.try_get::<U16>()?.get(), s.try_get::<U16>()?.get())))
+    ///     .unwrap();
+    /// assert_eq!((a, b), (1, 2));
+    /// assert_eq!(data, b"\xff");
+    ///
+    /// let mut data: &[u8] = &b"\x00\x01\x00"[..];
+    /// let err = data.with_section(3, |s| Ok(s.try_get::<U16>()?.get())).unwrap_err();
+    /// assert!(matches!(err, Error::Leftover { offset: 2, remaining: 1, .. }));
+    /// ```
+    fn with_section<R>(
+        &mut self,
+        len: usize,
+        f: impl FnOnce(&mut Self) -> Result<R, Error>,
+    ) -> Result<R, Error>
+    where
+        Self: Sized,
+    {
+        let mut section = self.try_split_section(len)?;
+        let r = f(&mut section).map_err(Error::overrun)?;
+        match section.remaining() {
+            0 => Ok(r),
+            n => Err(Error::leftover::<[u8]>(n).at(len - n)),
+        }
+    }
+
+    /// Get a [`QuicVarInt`] from the [`Buf`].
+    ///
+    /// Like [`ZeroCopyReadBuf::try_read_quic_varint`], but built on [`ZeroCopyBuf::try_get_elems`],
+    /// so that wrappers such as [`Tracked`] see the bytes being read.
+    /// This is useful to read the count for a following [`ZeroCopyBuf::try_get_elems`].
+    ///
+    /// ```
+    /// use zerocopy_buf::ZeroCopyBuf;
+    ///
+    /// let mut data = bytes::Bytes::from_static(b"\x40\x02hi");
+    /// let len = data.try_get_quic_varint().unwrap().to_usize();
+    /// assert_eq!(&*data.try_get_elems::<[u8]>(len).unwrap(), b"hi");
+    /// ```
+    fn try_get_quic_varint(&mut self) -> Result<QuicVarInt, Error> {
+        let available = self.remaining();
+        let first = self
+            .try_peek::<u8>()
+            .map_err(|e| Error::size::<QuicVarInt>(1, available).at(e.offset()))?;
+
+        let len = QuicVarInt::decoded_len(*first);
+        let bytes = self
+            .try_get_elems::<[u8]>(len)
+            .map_err(|e| Error::size::<QuicVarInt>(len, available).at(e.offset()))?;
+        Ok(QuicVarInt::decode(&bytes))
+    }
+
+    /// Get a UTF-8 string of `len` bytes from the [`Buf`].
+    ///
+    /// If [`Buf::remaining`] is greater than or equal to `len` and the bytes are valid UTF-8,
+    /// then a [`ByteStr`] is returned and the buffer is advanced by `len`, without copying.
+    ///
+    /// If [`Buf::remaining`] is less than `len`, an [`Error::Size`] is returned.
+    /// If the bytes are not valid UTF-8, an [`Error::Utf8`] is returned.
+    ///
+    /// ```
+    /// use zerocopy_buf::{Error, ZeroCopyBuf};
+    ///
+    /// let mut data = bytes::Bytes::from_static(b"caf\xc3\xa9 \xff");
+    /// assert_eq!(data.try_get_str(5).unwrap(), "café");
+    ///
+    /// let err = data.try_get_str(2).unwrap_err();
+    /// assert!(matches!(err, Error::Utf8 { valid_up_to: 1, .. }));
+    /// ```
+    fn try_get_str(&mut self, len: usize) -> Result<ByteStr, Error>
+    where
+        Self: Sized + Into<Bytes>,
+    {
+        let available = self.remaining();
+        let bytes = self
+            .chunk()
+            .get(..len)
+            .ok_or(Error::size::<str>(len, available))?;
+        core::str::from_utf8(bytes).map_err(|e| Error::utf8::<str>(e.valid_up_to()))?;
+
+        let bytes = self.try_split_section(len)?.into();
+        // SAFETY: the bytes were validated above.
+        Ok(unsafe { ByteStr::from_utf8_unchecked(bytes) })
+    }
+
+    /// Get a NUL-terminated string from the [`Buf`].
+    ///
+    /// If there is a NUL byte, the buffer is advanced past it and the string before it is returned
+    /// as a [`CStrBuf`] backed by the same kind of buffer.
+    ///
+    /// If there is no NUL byte, an [`Error::Size`] is returned.
+    ///
+    /// ```
+    /// use zerocopy_buf::ZeroCopyBuf;
+    ///
+    /// let mut data: &[u8] = &b"hello\0world"[..];
+    /// assert_eq!(data.try_get_cstr().unwrap().to_bytes(), b"hello");
+    /// assert!(data.try_get_cstr().is_err());
+    /// assert_eq!(data, b"world");
+    /// ```
+    fn try_get_cstr(&mut self) -> Result<CStrBuf<Self>, Error>
+    where
+        Self: Sized,
+    {
+        cstr::get(self, None)
+    }
+
+    /// Get a string from a fixed-width field of `width` bytes, such as a `[u8; 16]` name.
+    ///
+    /// If [`Buf::remaining`] is greater than or equal to `width`, the buffer is advanced by `width`
+    /// and the string before the first NUL byte is returned. If there is no NUL byte,
+    /// the whole field is the string.
+    ///
+    /// If [`Buf::remaining`] is less than `width`, an [`Error::Size`] is returned.
+    ///
+    /// ```
+    /// use zerocopy_buf::ZeroCopyBuf;
+    ///
+    /// let mut data: &[u8] = &b"eth0\0\0\0\0abcdefgh"[..];
+    /// let a = data.try_get_cstr_in(8).unwrap();
+    /// let b = data.try_get_cstr_in(8).unwrap();
+    /// assert_eq!(a.to_bytes(), b"eth0");
+    /// assert_eq!(b.to_bytes(), b"abcdefgh");
+    /// assert!(b.as_cstr().is_none());
+    /// ```
+    fn try_get_cstr_in(&mut self, width: usize) -> Result<CStrBuf<Self>, Error>
+    where
+        Self: Sized,
+    {
+        cstr::get(self, Some(width))
+    }
+
+    /// Iterate over all the `T` records in the [`Buf`].
+    ///
+    /// If [`Buf::remaining`] is not a multiple of the size of `T`,
+    /// the trailing bytes are available from [`Records::remainder`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` is zero-sized.
+    ///
+    /// ```
+    /// use zerocopy_buf::ZeroCopyBuf;
+    ///
+    /// let data: &[u8] = &b"\x00\x01\x00\x02\x00\x03\xff"[..];
+    /// let mut records = data.records::<zerocopy::network_endian::U16>();
+    /// assert_eq!(records.len(), 3);
+    /// assert_eq!(records.remainder(), b"\xff");
+    ///
+    /// assert_eq!(records.next().unwrap().get(), 1);
+    /// assert_eq!(records.next_back().unwrap().get(), 3);
+    /// assert_eq!(records.next().unwrap().get(), 2);
+    /// assert!(records.next().is_none());
+    /// ```
+    fn records<T: KnownLayout + Immutable + Unaligned>(self) -> Records<Self, T>
+    where
+        Self: Sized,
+    {
+        Records::new(self)
+    }
+
+    /// Run a multi-step parse, restoring the buffer if it fails.
+    ///
+    /// Anything consumed by `f` stays consumed only if it returns `Ok`.
+    /// If it returns `Err`, the buffer is restored to where it was before the call.
+    ///
+    /// The buffer is cloned before calling `f`, so this is only available for [`Rewind`]
+    /// buffers, which are cheap to clone.
+    ///
+    /// ```
+    /// use zerocopy_buf::{Error, ZeroCopyBuf};
+    /// use zerocopy::network_endian::{U16, U32};
+    ///
+    /// let mut data = bytes::Bytes::from_static(b"\x00\x01\x00\x00");
+    /// let res = data.transaction(|b| {
+    ///     let kind = b.try_get::<U16>()?;
+    ///     let len = b.try_get::<U32>()?;
+    ///     Ok::<_, Error>((kind.get(), len.get()))
+    /// });
+    ///
+    /// assert!(res.is_err());
+    /// assert_eq!(data.len(), 4);
+    /// ```
+    fn transaction<R, E>(&mut self, f: impl FnOnce(&mut Self) -> Result<R, E>) -> Result<R, E>
+    where
+        Self: Sized + Rewind,
+    {
+        let backup = self.clone();
+        let res = f(self);
+        if res.is_err() {
+            *self = backup;
+        }
+        res
+    }
+
+    /// Run a multi-step parse on a buffer that may not have received the whole message yet.
+    ///
+    /// Like [`ZeroCopyBuf::transaction`], the buffer is left untouched unless the parse is
+    /// [`Parsed::Complete`]. Any [`Error::Size`] returned by `f`, including from nested gets,
+    /// becomes [`Parsed::Incomplete`] with the number of additional bytes needed.
+    /// Reads that run past the end of a [`ZeroCopyBuf::with_section`] are [`Parsed::Invalid`].
+    ///
+    /// A [`BytesMut`] receive buffer is parsed through a `&[u8]` view,
+    /// and only advanced once the message is complete.
+    ///
+    /// ```
+    /// use bytes::{Buf, BufMut, BytesMut};
+    /// use zerocopy_buf::{Parsed, ZeroCopyBuf};
+    /// use zerocopy::network_endian::U16;
+    ///
+    /// fn parse(b: &mut &[u8]) -> Result<Vec<u8>, zerocopy_buf::Error> {
+    ///     let len = b.try_get::<U16>()?.get();
+    ///     Ok(b.try_get_elems::<[u8]>(len as usize)?.to_vec())
+    /// }
+    ///
+    /// let mut buf = BytesMut::from(&b"\x00\x05he"[..]);
+    /// let mut view = &buf[..];
+    /// assert_eq!(view.parse_streaming(parse), Parsed::Incomplete { needed: 3 });
+    ///
+    /// buf.put_slice(b"llo!");
+    /// let mut view = &buf[..];
+    /// assert_eq!(view.parse_streaming(parse), Parsed::Complete(b"hello".to_vec()));
+    ///
+    /// let consumed = buf.len() - view.len();
+    /// buf.advance(consumed);
+    /// assert_eq!(buf, b"!"[..]);
+    /// ```
+    fn parse_streaming<R>(&mut self, f: impl FnOnce(&mut Self) -> Result<R, Error>) -> Parsed<R>
+    where
+        Self: Sized + Rewind,
+    {
+        self.transaction(f).into()
+    }
+}
+
+/// A [`BufMut`] that uses [`zerocopy::IntoBytes`] to encode
+pub trait ZeroCopyBufMut: BufMut {
+    /// Write a `T` to the [`BufMut`].
+    ///
+    /// This single method imitates all of the `BufMut::put_...` methods.
+    /// For example, [`BufMut::put_u16`] could be written as:
+    /// ```
+    /// use zerocopy_buf::ZeroCopyBufMut;
+    ///
+    /// let mut data = bytes::BytesMut::new();
+    /// data.write(&zerocopy::network_endian::U16::new(0x0102));
+    /// assert_eq!(&data, &b"\x01\x02"[..]);
+    /// ```
+    fn write<T: IntoBytes + Immutable>(&mut self, t: &T);
+
+    /// Write a native `T` encoded in the byte order `O` to the [`BufMut`].
+    ///
+    /// ```
+    /// use zerocopy_buf::ZeroCopyBufMut;
+    /// use zerocopy::byteorder::NetworkEndian;
+    ///
+    /// let mut data = bytes::BytesMut::new();
+    /// data.write_endian::<NetworkEndian, _>(0x0102u16);
+    /// assert_eq!(&data, &b"\x01\x02"[..]);
+    /// ```
+    fn write_endian<O: ByteOrder, T: Primitive>(&mut self, t: T) {
+        self.write(&t.to_wrapper::<O>());
+    }
+
+    /// Write a big-endian `T` to the [`BufMut`].
+    ///
+    /// See [`ZeroCopyBufMut::write_endian`].
+    fn write_be<T: Primitive>(&mut self, t: T) {
+        self.write_endian::<BigEndian, T>(t);
+    }
+
+    /// Write a little-endian `T` to the [`BufMut`].
+    ///
+    /// See [`ZeroCopyBufMut::write_endian`].
+    fn write_le<T: Primitive>(&mut self, t: T) {
+        self.write_endian::<LittleEndian, T>(t);
+    }
+
+    /// Write a `T` in the byte order `endian` that was chosen at runtime.
+    ///
+    /// ```
+    /// use zerocopy_buf::{Endian, ZeroCopyBufMut};
+    ///
+    /// let mut data = bytes::BytesMut::new();
+    /// data.write_dyn(0x0102u16, Endian::Little);
+    /// assert_eq!(&data, &b"\x02\x01"[..]);
+    /// ```
+    fn write_dyn<T: Primitive>(&mut self, t: T, endian: Endian) {
+        self.write(&DynEndian::new(t, endian));
+    }
+
+    /// Write an unsigned LEB128 varint to the [`BufMut`].
+    ///
+    /// ```
+    /// use zerocopy_buf::ZeroCopyBufMut;
+    ///
+    /// let mut data = bytes::BytesMut::new();
+    /// data.write_uvarint(300u16);
+    /// assert_eq!(&data, &b"\xac\x02"[..]);
+    /// ```
+    fn write_uvarint<T: Into<u64>>(&mut self, t: T) {
+        varint::write(self, t.into());
+    }
+
+    /// Write a zig-zag encoded signed LEB128 varint to the [`BufMut`].
+    ///
+    /// ```
+    /// use zerocopy_buf::ZeroCopyBufMut;
+    ///
+    /// let mut data = bytes::BytesMut::new();
+    /// data.write_svarint(-2i32);
+    /// assert_eq!(&data, &b"\x03"[..]);
+    /// ```
+    fn write_svarint<T: Into<i64>>(&mut self, t: T) {
+        varint::write(self, varint::zigzag_encode(t.into()));
+    }
+
+    /// Write a [`QuicVarInt`] to the [`BufMut`] using the shortest encoding.
+    ///
+    /// ```
+    /// use zerocopy_buf::{QuicVarInt, ZeroCopyBufMut};
+    ///
+    /// let mut data = bytes::BytesMut::new();
+    /// data.write_quic_varint(QuicVarInt::from(15293u16));
+    /// assert_eq!(&data, &b"\x7b\xbd"[..]);
+    /// ```
+    fn write_quic_varint(&mut self, n: QuicVarInt) {
+        self.write_quic_varint_with_len(n, n.encoded_len());
+    }
+
+    /// Write a [`QuicVarInt`] to the [`BufMut`] using exactly `len` bytes.
+    ///
+    /// This is useful to reserve space for a length that is filled in later.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is not 1, 2, 4 or 8, or is smaller than [`QuicVarInt::encoded_len`].
+    ///
+    /// ```
+    /// use zerocopy_buf::{QuicVarInt, ZeroCopyBufMut};
+    ///
+    /// let mut data = bytes::BytesMut::new();
+    /// data.write_quic_varint_with_len(QuicVarInt::from(37u8), 2);
+    /// assert_eq!(&data, &b"\x40\x25"[..]);
+    /// ```
+    fn write_quic_varint_with_len(&mut self, n: QuicVarInt, len: usize) {
+        let mut buf = [0; 8];
+        self.put_slice(n.encode(len, &mut buf));
+    }
+
+    /// Write `bytes` to the [`BufMut`], prefixed by its length as an `L`.
+    ///
+    /// If the length does not fit in `L`, an [`Error::TooLarge`] is returned and nothing is written.
+    ///
+    /// ```
+    /// use zerocopy_buf::{Error, ZeroCopyBufMut};
+    ///
+    /// let mut data = bytes::BytesMut::new();
+    /// data.write_prefixed::<u8>(b"hello").unwrap();
+    /// assert_eq!(&data, &b"\x05hello"[..]);
+    ///
+    /// let err = data.write_prefixed::<u8>(&[0; 256]).unwrap_err();
+    /// assert!(matches!(err, Error::TooLarge { size: 256, max: 255, .. }));
+    /// ```
+    fn write_prefixed<L: Length>(&mut self, bytes: &[u8]) -> Result<(), Error> {
+        let len = L::from_usize(bytes.len()).ok_or(Error::too_large::<L>(bytes.len(), L::MAX))?;
+        self.write(&len);
+        self.put_slice(bytes);
+        Ok(())
+    }
+}
+
+impl ZeroCopyReadBuf for Bytes {}
+
+impl ZeroCopyReadBuf for BytesMut {}
+
+impl ZeroCopyReadBuf for &[u8] {}
+
+impl ZeroCopyReadBuf for VecDeque<u8> {}
+
+#[cfg(feature = "std")]
+impl<T: AsRef<[u8]>> ZeroCopyReadBuf for std::io::Cursor<T> {}
+
+impl<T: Buf, U: Buf> ZeroCopyReadBuf for Chain<T, U> {}
+
+impl<T: ZeroCopyReadBuf> ZeroCopyReadBuf for Take<T> {
+    fn read_offset(&self) -> usize {
+        self.get_ref().read_offset()
+    }
+
+    fn record_read<R: ?Sized>(&mut self, size: usize) {
+        self.get_mut().record_read::<R>(size);
+    }
+}
+
+impl<T: ZeroCopyReadBuf> ZeroCopyReadBuf for &mut T {
+    fn read_offset(&self) -> usize {
+        (**self).read_offset()
+    }
+
+    fn record_read<R: ?Sized>(&mut self, size: usize) {
+        (**self).record_read::<R>(size);
+    }
+}
+
+impl<T: ZeroCopyReadBuf> ZeroCopyReadBuf for Box<T> {
+    fn read_offset(&self) -> usize {
+        (**self).read_offset()
+    }
+
+    fn record_read<R: ?Sized>(&mut self, size: usize) {
+        (**self).record_read::<R>(size);
+    }
+}
+
+impl ZeroCopyBuf for Bytes {
+    type Buf = ByteSlice<Bytes>;
+
+    fn try_get<T: KnownLayout + Immutable + Unaligned>(&mut self) -> Res<Self::Buf, T> {
+        let (a, b) = Ref::from_prefix(ByteSlice(mem::take(self)))
+            .map_err(|e| e.map_src(|s| *self = s.0))
+            .map_err(|e| Error::from(e.map_src(|()| &**self)))?;
+        *self = b.0;
+        Ok(a)
+    }
+
+    fn try_get_elems<T: KnownLayout<PointerMetadata = usize> + Immutable + Unaligned + ?Sized>(
+        &mut self,
+        count: usize,
+    ) -> Res<Self::Buf, T> {
+        let (a, b) = Ref::from_prefix_with_elems(ByteSlice(mem::take(self)), count)
+            .map_err(|e| e.map_src(|s| *self = s.0))
+            .map_err(|e| Error::from(e.map_src(|()| &**self)).with_elems::<T>(count))?;
+        *self = b.0;
+        Ok(a)
+    }
+
+    fn try_peek<T: KnownLayout + Immutable + Unaligned>(&self) -> Res<&[u8], T> {
+        let (a, _) = Ref::from_prefix(&**self)?;
+        Ok(a)
     }
 
     fn try_peek_elems<T: KnownLayout<PointerMetadata = usize> + Immutable + Unaligned + ?Sized>(
+        &self,
+        count: usize,
+    ) -> Res<&[u8], T> {
+        let (a, _) = Ref::from_prefix_with_elems(&**self, count)
+            .map_err(|e| Error::from(e).with_elems::<T>(count))?;
+        Ok(a)
+    }
+
+    fn try_get_checked<T: TryFromBytes + KnownLayout + Immutable + Unaligned>(
+        &mut self,
+    ) -> TryRes<Self::Buf, T> {
+        let (a, b) = TryRef::try_from_prefix(ByteSlice(mem::take(self)))
+            .map_err(|e| e.map_src(|s| *self = s.0))
+            .map_err(|e| Error::from(e.map_src(|()| &**self)))?;
+        *self = b.0;
+        Ok(a)
+    }
+
+    fn try_get_elems_checked<
+        T: TryFromBytes + KnownLayout<PointerMetadata = usize> + Immutable + Unaligned + ?Sized,
+    >(
+        &mut self,
+        count: usize,
+    ) -> TryRes<Self::Buf, T> {
+        let (a, b) = TryRef::try_from_prefix_with_elems(ByteSlice(mem::take(self)), count)
+            .map_err(|e| e.map_src(|s| *self = s.0))
+            .map_err(|e| Error::from(e.map_src(|()| &**self)).with_elems::<T>(count))?;
+        *self = b.0;
+        Ok(a)
+    }
+
+    fn try_peek_checked<T: TryFromBytes + KnownLayout + Immutable + Unaligned>(
+        &self,
+    ) -> TryRes<&[u8], T> {
+        let (a, _) = TryRef::try_from_prefix(&**self)?;
+        Ok(a)
+    }
+
+    fn try_peek_elems_checked<
+        T: TryFromBytes + KnownLayout<PointerMetadata = usize> + Immutable + Unaligned + ?Sized,
+    >(
+        &self,
+        count: usize,
+    ) -> TryRes<&[u8], T> {
+        let (a, _) = TryRef::try_from_prefix_with_elems(&**self, count)
+            .map_err(|e| Error::from(e).with_elems::<T>(count))?;
+        Ok(a)
+    }
+
+    fn try_get_aligned<T: KnownLayout + Immutable>(&mut self) -> Res<Self::Buf, T> {
+        let (a, b) = Ref::from_prefix(ByteSlice(mem::take(self)))
+            .map_err(|e| e.map_src(|s| *self = s.0))
+            .map_err(|e| Error::from(e.map_src(|()| &**self)))?;
+        *self = b.0;
+        Ok(a)
+    }
+
+    fn try_get_elems_aligned<T: KnownLayout<PointerMetadata = usize> + Immutable + ?Sized>(
+        &mut self,
+        count: usize,
+    ) -> Res<Self::Buf, T> {
+        let (a, b) = Ref::from_prefix_with_elems(ByteSlice(mem::take(self)), count)
+            .map_err(|e| e.map_src(|s| *self = s.0))
+            .map_err(|e| Error::from(e.map_src(|()| &**self)).with_elems::<T>(count))?;
+        *self = b.0;
+        Ok(a)
+    }
+
+    fn try_peek_aligned<T: KnownLayout + Immutable>(&self) -> Res<&[u8], T> {
+        let (a, _) = Ref::from_prefix(&**self)?;
+        Ok(a)
+    }
+
+    fn try_peek_elems_aligned<T: KnownLayout<PointerMetadata = usize> + Immutable + ?Sized>(
+        &self,
+        count: usize,
+    ) -> Res<&[u8], T> {
+        let (a, _) = Ref::from_prefix_with_elems(&**self, count)
+            .map_err(|e| Error::from(e).with_elems::<T>(count))?;
+        Ok(a)
+    }
+
+    fn try_get_suffix<T: KnownLayout + Immutable + Unaligned>(&mut self) -> Res<Self::Buf, T> {
+        let (_, a) = Ref::<_, T>::from_suffix(&**self)?;
+        let at = self.len() - Ref::bytes(&a).len();
+        Ok(Ref::from_bytes(ByteSlice(self.split_off(at)))?)
+    }
+
+    fn try_get_suffix_elems<
+        T: KnownLayout<PointerMetadata = usize> + Immutable + Unaligned + ?Sized,
+    >(
+        &mut self,
+        count: usize,
+    ) -> Res<Self::Buf, T> {
+        let (_, a) = Ref::<_, T>::from_suffix_with_elems(&**self, count)
+            .map_err(|e| Error::from(e).with_elems::<T>(count))?;
+        let at = self.len() - Ref::bytes(&a).len();
+        Ok(Ref::from_bytes_with_elems(
+            ByteSlice(self.split_off(at)),
+            count,
+        )?)
+    }
+
+    fn try_peek_suffix<T: KnownLayout + Immutable + Unaligned>(&self) -> Res<&[u8], T> {
+        let (_, a) = Ref::from_suffix(&**self)?;
+        Ok(a)
+    }
+
+    fn try_split_section(&mut self, len: usize) -> Result<Self, Error> {
+        if self.len() < len {
+            return Err(Error::size::<[u8]>(len, self.len()));
+        }
+        Ok(self.split_to(len))
+    }
+}
+
+impl ZeroCopyBuf for BytesMut {
+    type Buf = ByteSlice<BytesMut>;
+
+    fn try_get<T: KnownLayout + Immutable + Unaligned>(&mut self) -> Res<Self::Buf, T> {
+        let (a, b) = Ref::from_prefix(ByteSlice(mem::take(self)))
+            .map_err(|e| e.map_src(|s| *self = s.0))
+            .map_err(|e| Error::from(e.map_src(|()| &**self)))?;
+        *self = b.0;
+        Ok(a)
+    }
+
+    fn try_get_elems<T: KnownLayout<PointerMetadata = usize> + Immutable + Unaligned + ?Sized>(
         &mut self,
         count: usize,
+    ) -> Res<Self::Buf, T> {
+        let (a, b) = Ref::from_prefix_with_elems(ByteSlice(mem::take(self)), count)
+            .map_err(|e| e.map_src(|s| *self = s.0))
+            .map_err(|e| Error::from(e.map_src(|()| &**self)).with_elems::<T>(count))?;
+        *self = b.0;
+        Ok(a)
+    }
+
+    fn try_peek<T: KnownLayout + Immutable + Unaligned>(&self) -> Res<&[u8], T> {
+        let (a, _) = Ref::from_prefix(&**self)?;
+        Ok(a)
+    }
+
+    fn try_peek_elems<T: KnownLayout<PointerMetadata = usize> + Immutable + Unaligned + ?Sized>(
+        &self,
+        count: usize,
     ) -> Res<&[u8], T> {
-        let (a, _) = Ref::from_prefix_with_elems(*self, count).map_err(SizeError::from)?;
+        let (a, _) = Ref::from_prefix_with_elems(&**self, count)
+            .map_err(|e| Error::from(e).with_elems::<T>(count))?;
+        Ok(a)
+    }
+
+    fn try_get_checked<T: TryFromBytes + KnownLayout + Immutable + Unaligned>(
+        &mut self,
+    ) -> TryRes<Self::Buf, T> {
+        let (a, b) = TryRef::try_from_prefix(ByteSlice(mem::take(self)))
+            .map_err(|e| e.map_src(|s| *self = s.0))
+            .map_err(|e| Error::from(e.map_src(|()| &**self)))?;
+        *self = b.0;
+        Ok(a)
+    }
+
+    fn try_get_elems_checked<
+        T: TryFromBytes + KnownLayout<PointerMetadata = usize> + Immutable + Unaligned + ?Sized,
+    >(
+        &mut self,
+        count: usize,
+    ) -> TryRes<Self::Buf, T> {
+        let (a, b) = TryRef::try_from_prefix_with_elems(ByteSlice(mem::take(self)), count)
+            .map_err(|e| e.map_src(|s| *self = s.0))
+            .map_err(|e| Error::from(e.map_src(|()| &**self)).with_elems::<T>(count))?;
+        *self = b.0;
+        Ok(a)
+    }
+
+    fn try_peek_checked<T: TryFromBytes + KnownLayout + Immutable + Unaligned>(
+        &self,
+    ) -> TryRes<&[u8], T> {
+        let (a, _) = TryRef::try_from_prefix(&**self)?;
+        Ok(a)
+    }
+
+    fn try_peek_elems_checked<
+        T: TryFromBytes + KnownLayout<PointerMetadata = usize> + Immutable + Unaligned + ?Sized,
+    >(
+        &self,
+        count: usize,
+    ) -> TryRes<&[u8], T> {
+        let (a, _) = TryRef::try_from_prefix_with_elems(&**self, count)
+            .map_err(|e| Error::from(e).with_elems::<T>(count))?;
+        Ok(a)
+    }
+
+    fn try_get_aligned<T: KnownLayout + Immutable>(&mut self) -> Res<Self::Buf, T> {
+        let (a, b) = Ref::from_prefix(ByteSlice(mem::take(self)))
+            .map_err(|e| e.map_src(|s| *self = s.0))
+            .map_err(|e| Error::from(e.map_src(|()| &**self)))?;
+        *self = b.0;
+        Ok(a)
+    }
+
+    fn try_get_elems_aligned<T: KnownLayout<PointerMetadata = usize> + Immutable + ?Sized>(
+        &mut self,
+        count: usize,
+    ) -> Res<Self::Buf, T> {
+        let (a, b) = Ref::from_prefix_with_elems(ByteSlice(mem::take(self)), count)
+            .map_err(|e| e.map_src(|s| *self = s.0))
+            .map_err(|e| Error::from(e.map_src(|()| &**self)).with_elems::<T>(count))?;
+        *self = b.0;
+        Ok(a)
+    }
+
+    fn try_peek_aligned<T: KnownLayout + Immutable>(&self) -> Res<&[u8], T> {
+        let (a, _) = Ref::from_prefix(&**self)?;
+        Ok(a)
+    }
+
+    fn try_peek_elems_aligned<T: KnownLayout<PointerMetadata = usize> + Immutable + ?Sized>(
+        &self,
+        count: usize,
+    ) -> Res<&[u8], T> {
+        let (a, _) = Ref::from_prefix_with_elems(&**self, count)
+            .map_err(|e| Error::from(e).with_elems::<T>(count))?;
+        Ok(a)
+    }
+
+    fn try_get_suffix<T: KnownLayout + Immutable + Unaligned>(&mut self) -> Res<Self::Buf, T> {
+        let (_, a) = Ref::<_, T>::from_suffix(&**self)?;
+        let at = self.len() - Ref::bytes(&a).len();
+        Ok(Ref::from_bytes(ByteSlice(self.split_off(at)))?)
+    }
+
+    fn try_get_suffix_elems<
+        T: KnownLayout<PointerMetadata = usize> + Immutable + Unaligned + ?Sized,
+    >(
+        &mut self,
+        count: usize,
+    ) -> Res<Self::Buf, T> {
+        let (_, a) = Ref::<_, T>::from_suffix_with_elems(&**self, count)
+            .map_err(|e| Error::from(e).with_elems::<T>(count))?;
+        let at = self.len() - Ref::bytes(&a).len();
+        Ok(Ref::from_bytes_with_elems(
+            ByteSlice(self.split_off(at)),
+            count,
+        )?)
+    }
+
+    fn try_peek_suffix<T: KnownLayout + Immutable + Unaligned>(&self) -> Res<&[u8], T> {
+        let (_, a) = Ref::from_suffix(&**self)?;
+        Ok(a)
+    }
+
+    fn try_split_section(&mut self, len: usize) -> Result<Self, Error> {
+        if self.len() < len {
+            return Err(Error::size::<[u8]>(len, self.len()));
+        }
+        Ok(self.split_to(len))
+    }
+}
+
+impl ZeroCopyBuf for &[u8] {
+    type Buf = Self;
+
+    fn try_get<T: KnownLayout + Immutable + Unaligned>(&mut self) -> Res<Self::Buf, T> {
+        let (a, b) = Ref::from_prefix(*self)?;
+        *self = b;
+        Ok(a)
+    }
+
+    fn try_get_elems<T: KnownLayout<PointerMetadata = usize> + Immutable + Unaligned + ?Sized>(
+        &mut self,
+        count: usize,
+    ) -> Res<Self::Buf, T> {
+        let (a, b) = Ref::from_prefix_with_elems(*self, count)
+            .map_err(|e| Error::from(e).with_elems::<T>(count))?;
+        *self = b;
+        Ok(a)
+    }
+
+    fn try_peek<T: KnownLayout + Immutable + Unaligned>(&self) -> Res<&[u8], T> {
+        let (a, _) = Ref::from_prefix(*self)?;
+        Ok(a)
+    }
+
+    fn try_peek_elems<T: KnownLayout<PointerMetadata = usize> + Immutable + Unaligned + ?Sized>(
+        &self,
+        count: usize,
+    ) -> Res<&[u8], T> {
+        let (a, _) = Ref::from_prefix_with_elems(*self, count)
+            .map_err(|e| Error::from(e).with_elems::<T>(count))?;
+        Ok(a)
+    }
+
+    fn try_get_checked<T: TryFromBytes + KnownLayout + Immutable + Unaligned>(
+        &mut self,
+    ) -> TryRes<Self::Buf, T> {
+        let (a, b) = TryRef::try_from_prefix(*self)?;
+        *self = b;
+        Ok(a)
+    }
+
+    fn try_get_elems_checked<
+        T: TryFromBytes + KnownLayout<PointerMetadata = usize> + Immutable + Unaligned + ?Sized,
+    >(
+        &mut self,
+        count: usize,
+    ) -> TryRes<Self::Buf, T> {
+        let (a, b) = TryRef::try_from_prefix_with_elems(*self, count)
+            .map_err(|e| Error::from(e).with_elems::<T>(count))?;
+        *self = b;
+        Ok(a)
+    }
+
+    fn try_peek_checked<T: TryFromBytes + KnownLayout + Immutable + Unaligned>(
+        &self,
+    ) -> TryRes<&[u8], T> {
+        let (a, _) = TryRef::try_from_prefix(*self)?;
+        Ok(a)
+    }
+
+    fn try_peek_elems_checked<
+        T: TryFromBytes + KnownLayout<PointerMetadata = usize> + Immutable + Unaligned + ?Sized,
+    >(
+        &self,
+        count: usize,
+    ) -> TryRes<&[u8], T> {
+        let (a, _) = TryRef::try_from_prefix_with_elems(*self, count)
+            .map_err(|e| Error::from(e).with_elems::<T>(count))?;
+        Ok(a)
+    }
+
+    fn try_get_aligned<T: KnownLayout + Immutable>(&mut self) -> Res<Self::Buf, T> {
+        let (a, b) = Ref::from_prefix(*self)?;
+        *self = b;
+        Ok(a)
+    }
+
+    fn try_get_elems_aligned<T: KnownLayout<PointerMetadata = usize> + Immutable + ?Sized>(
+        &mut self,
+        count: usize,
+    ) -> Res<Self::Buf, T> {
+        let (a, b) = Ref::from_prefix_with_elems(*self, count)
+            .map_err(|e| Error::from(e).with_elems::<T>(count))?;
+        *self = b;
+        Ok(a)
+    }
+
+    fn try_peek_aligned<T: KnownLayout + Immutable>(&self) -> Res<&[u8], T> {
+        let (a, _) = Ref::from_prefix(*self)?;
+        Ok(a)
+    }
+
+    fn try_peek_elems_aligned<T: KnownLayout<PointerMetadata = usize> + Immutable + ?Sized>(
+        &self,
+        count: usize,
+    ) -> Res<&[u8], T> {
+        let (a, _) = Ref::from_prefix_with_elems(*self, count)
+            .map_err(|e| Error::from(e).with_elems::<T>(count))?;
+        Ok(a)
+    }
+
+    fn try_get_suffix<T: KnownLayout + Immutable + Unaligned>(&mut self) -> Res<Self::Buf, T> {
+        let (b, a) = Ref::from_suffix(*self)?;
+        *self = b;
+        Ok(a)
+    }
+
+    fn try_get_suffix_elems<
+        T: KnownLayout<PointerMetadata = usize> + Immutable + Unaligned + ?Sized,
+    >(
+        &mut self,
+        count: usize,
+    ) -> Res<Self::Buf, T> {
+        let (b, a) = Ref::from_suffix_with_elems(*self, count)
+            .map_err(|e| Error::from(e).with_elems::<T>(count))?;
+        *self = b;
+        Ok(a)
+    }
+
+    fn try_peek_suffix<T: KnownLayout + Immutable + Unaligned>(&self) -> Res<&[u8], T> {
+        let (_, a) = Ref::from_suffix(*self)?;
+        Ok(a)
+    }
+
+    fn try_split_section(&mut self, len: usize) -> Result<Self, Error> {
+        if self.len() < len {
+            return Err(Error::size::<[u8]>(len, self.len()));
+        }
+        let (a, b) = self.split_at(len);
+        *self = b;
         Ok(a)
     }
 }
@@ -36,3 +36,25 @@ pub fn as_bytes_mut<T>(this: &mut MaybeUninit<T>) -> &mut [MaybeUninit<u8>] {
         )
     }
 }
+
+/// Same as [`MaybeUninit::as_bytes_mut`], but for a slice of `T`
+pub fn slice_as_bytes_mut<T>(this: &mut [MaybeUninit<T>]) -> &mut [MaybeUninit<u8>] {
+    // SAFETY: MaybeUninit<u8> is always valid, even for padding bytes
+    unsafe {
+        slice::from_raw_parts_mut(
+            this.as_mut_ptr() as *mut MaybeUninit<u8>,
+            mem::size_of_val(this),
+        )
+    }
+}
+
+/// View the bytes of any `T` as a slice of [`MaybeUninit<u8>`]
+///
+/// # Safety
+/// Only initialised bytes may be written to the slice,
+/// and the bytes written must be a valid `T`.
+pub unsafe fn as_uninit_bytes_mut<T: ?Sized>(this: &mut T) -> &mut [MaybeUninit<u8>] {
+    let len = mem::size_of_val(this);
+    // SAFETY: the caller guarantees that `T` will remain valid
+    unsafe { slice::from_raw_parts_mut(this as *mut T as *mut MaybeUninit<u8>, len) }
+}
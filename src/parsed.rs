@@ -0,0 +1,53 @@
+//! The outcome of parsing from a stream that may not have all of its bytes yet.
+
+use crate::Error;
+
+/// The outcome of [`ZeroCopyBuf::parse_streaming`](crate::ZeroCopyBuf::parse_streaming).
+///
+/// Unlike a plain [`Result`], this separates a message that is malformed
+/// from a message that is not fully received yet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Parsed<T> {
+    /// The value was parsed successfully.
+    Complete(T),
+    /// The buffer ended before the value was complete.
+    Incomplete {
+        /// The minimum number of additional bytes needed to make progress.
+        ///
+        /// More bytes may be needed after these arrive, for example once a length field can be read.
+        needed: usize,
+    },
+    /// The bytes could not be parsed.
+    Invalid(Error),
+}
+
+impl<T> Parsed<T> {
+    /// Maps the value of a [`Parsed::Complete`].
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Parsed<U> {
+        match self {
+            Parsed::Complete(t) => Parsed::Complete(f(t)),
+            Parsed::Incomplete { needed } => Parsed::Incomplete { needed },
+            Parsed::Invalid(e) => Parsed::Invalid(e),
+        }
+    }
+
+    /// Whether the value was parsed successfully.
+    pub fn is_complete(&self) -> bool {
+        matches!(self, Parsed::Complete(_))
+    }
+}
+
+impl<T> From<Result<T, Error>> for Parsed<T> {
+    /// An [`Error::Size`] is [`Parsed::Incomplete`], unless the size needed overflowed.
+    fn from(res: Result<T, Error>) -> Self {
+        match res {
+            Ok(t) => Parsed::Complete(t),
+            Err(Error::Size {
+                needed, available, ..
+            }) if needed != usize::MAX => Parsed::Incomplete {
+                needed: needed - available,
+            },
+            Err(e) => Parsed::Invalid(e),
+        }
+    }
+}
@@ -0,0 +1,72 @@
+//! Native integer and float types with a byte order chosen at the call site.
+
+use zerocopy::{
+    byteorder::ByteOrder, FromBytes, Immutable, IntoBytes, KnownLayout, Unaligned, F32, F64, I128,
+    I16, I32, I64, U128, U16, U32, U64,
+};
+
+/// A native integer or float type, such as `u32` or `f64`,
+/// that can be encoded in either byte order through its [`zerocopy::byteorder`] wrapper.
+pub trait Primitive: Copy {
+    /// The [`zerocopy::byteorder`] type for `Self` in the byte order `O`, such as [`U32<O>`].
+    type Wrapper<O: ByteOrder>: FromBytes + IntoBytes + KnownLayout + Immutable + Unaligned + Copy;
+
+    /// Convert from the byte order `O` to a native value.
+    fn from_wrapper<O: ByteOrder>(w: Self::Wrapper<O>) -> Self;
+
+    /// Convert from a native value to the byte order `O`.
+    fn to_wrapper<O: ByteOrder>(self) -> Self::Wrapper<O>;
+}
+
+impl Primitive for u8 {
+    type Wrapper<O: ByteOrder> = u8;
+
+    fn from_wrapper<O: ByteOrder>(w: u8) -> Self {
+        w
+    }
+
+    fn to_wrapper<O: ByteOrder>(self) -> u8 {
+        self
+    }
+}
+
+impl Primitive for i8 {
+    type Wrapper<O: ByteOrder> = i8;
+
+    fn from_wrapper<O: ByteOrder>(w: i8) -> Self {
+        w
+    }
+
+    fn to_wrapper<O: ByteOrder>(self) -> i8 {
+        self
+    }
+}
+
+macro_rules! impl_primitive {
+    ($($ty:ident => $wrapper:ident),*) => {$(
+        impl Primitive for $ty {
+            type Wrapper<O: ByteOrder> = $wrapper<O>;
+
+            fn from_wrapper<O: ByteOrder>(w: $wrapper<O>) -> Self {
+                w.get()
+            }
+
+            fn to_wrapper<O: ByteOrder>(self) -> $wrapper<O> {
+                $wrapper::new(self)
+            }
+        }
+    )*};
+}
+
+impl_primitive!(
+    u16 => U16,
+    u32 => U32,
+    u64 => U64,
+    u128 => U128,
+    i16 => I16,
+    i32 => I32,
+    i64 => I64,
+    i128 => I128,
+    f32 => F32,
+    f64 => F64
+);
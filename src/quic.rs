@@ -0,0 +1,127 @@
+//! QUIC variable-length integers, as defined in [RFC 9000 §16](https://www.rfc-editor.org/rfc/rfc9000#section-16).
+
+use core::fmt;
+
+/// An integer less than 2<sup>62</sup>, encoded in 1, 2, 4 or 8 bytes selected by the top two bits of the first byte.
+///
+/// Read with [`ZeroCopyReadBuf::try_read_quic_varint`](crate::ZeroCopyReadBuf::try_read_quic_varint)
+/// or [`ZeroCopyBuf::try_get_quic_varint`](crate::ZeroCopyBuf::try_get_quic_varint),
+/// and write with [`ZeroCopyBufMut::write_quic_varint`](crate::ZeroCopyBufMut::write_quic_varint).
+///
+/// ```
+/// use zerocopy_buf::{QuicVarInt, ZeroCopyBuf};
+///
+/// let mut data: &[u8] = &b"\x40\x03abc"[..];
+/// let len = data.try_get_quic_varint().unwrap();
+/// assert_eq!(len.encoded_len(), 1);
+///
+/// let body = data.try_get_elems::<[u8]>(len.to_usize()).unwrap();
+/// assert_eq!(&*body, b"abc");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct QuicVarInt(u64);
+
+impl QuicVarInt {
+    /// The largest value that can be encoded.
+    pub const MAX: QuicVarInt = QuicVarInt((1 << 62) - 1);
+
+    /// Create the value from `n`.
+    ///
+    /// Returns [`None`] if `n` is greater than [`QuicVarInt::MAX`].
+    pub const fn new(n: u64) -> Option<Self> {
+        if n <= Self::MAX.0 {
+            Some(QuicVarInt(n))
+        } else {
+            None
+        }
+    }
+
+    /// Create the value from a `u32`, which always fits.
+    pub const fn from_u32(n: u32) -> Self {
+        QuicVarInt(n as u64)
+    }
+
+    /// The value as a `u64`.
+    pub const fn get(self) -> u64 {
+        self.0
+    }
+
+    /// The value as a `usize`, saturating at [`usize::MAX`].
+    pub fn to_usize(self) -> usize {
+        usize::try_from(self.0).unwrap_or(usize::MAX)
+    }
+
+    /// The number of bytes in the shortest encoding of the value.
+    pub const fn encoded_len(self) -> usize {
+        match self.0 {
+            0..0x40 => 1,
+            0x40..0x4000 => 2,
+            0x4000..0x4000_0000 => 4,
+            _ => 8,
+        }
+    }
+
+    /// The number of bytes in an encoding that starts with `first`.
+    pub const fn decoded_len(first: u8) -> usize {
+        1 << (first >> 6)
+    }
+
+    /// Decode a value from exactly [`QuicVarInt::decoded_len`] bytes.
+    pub(crate) fn decode(bytes: &[u8]) -> Self {
+        let mut buf = [0; 8];
+        buf[8 - bytes.len()..].copy_from_slice(bytes);
+        let bits = bytes.len() * 8 - 2;
+        QuicVarInt(u64::from_be_bytes(buf) & (u64::MAX >> (64 - bits)))
+    }
+
+    /// Encode the value in `len` bytes of `buf`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is not 1, 2, 4 or 8, or is smaller than [`QuicVarInt::encoded_len`].
+    pub(crate) fn encode(self, len: usize, buf: &mut [u8; 8]) -> &[u8] {
+        assert!(
+            len.is_power_of_two() && len <= 8 && len >= self.encoded_len(),
+            "{self:?} cannot be encoded in {len} bytes"
+        );
+        let tag = u64::from(len.trailing_zeros()) << (len * 8 - 2);
+        *buf = (self.0 | tag).to_be_bytes();
+        &buf[8 - len..]
+    }
+}
+
+impl From<u8> for QuicVarInt {
+    fn from(n: u8) -> Self {
+        QuicVarInt(n.into())
+    }
+}
+
+impl From<u16> for QuicVarInt {
+    fn from(n: u16) -> Self {
+        QuicVarInt(n.into())
+    }
+}
+
+impl From<u32> for QuicVarInt {
+    fn from(n: u32) -> Self {
+        QuicVarInt(n.into())
+    }
+}
+
+impl From<QuicVarInt> for u64 {
+    fn from(n: QuicVarInt) -> Self {
+        n.0
+    }
+}
+
+impl fmt::Debug for QuicVarInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("QuicVarInt").field(&self.0).finish()
+    }
+}
+
+impl fmt::Display for QuicVarInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
@@ -0,0 +1,135 @@
+//! The reads behind [`ZeroCopyReadBuf`], which work on any [`Buf`].
+
+use alloc::{boxed::Box, vec::Vec};
+use core::mem;
+
+use bytes::Buf;
+use zerocopy::{FromBytes, KnownLayout, TryFromBytes};
+
+use crate::{buf_polyfill, layout, mu_polyfill, Error, QuicVarInt, ZeroCopyReadBuf};
+
+/// Run a read of a `T` through the hooks of `this`.
+///
+/// Errors are moved to [`ZeroCopyReadBuf::read_offset`], and the bytes consumed by a successful read
+/// are passed to [`ZeroCopyReadBuf::record_read`].
+pub(crate) fn with_hooks<B: ZeroCopyReadBuf, T: ?Sized, R>(
+    this: &mut B,
+    read: impl FnOnce(&mut B) -> Result<R, Error>,
+) -> Result<R, Error> {
+    let offset = this.read_offset();
+    let available = this.remaining();
+    let r = read(this).map_err(|e| e.at(offset))?;
+    this.record_read::<T>(available - this.remaining());
+    Ok(r)
+}
+
+pub(crate) fn read<T: FromBytes>(this: &mut impl Buf) -> Result<T, Error> {
+    let available = this.remaining();
+    let mut t = mem::MaybeUninit::<T>::uninit();
+    let bytes = buf_polyfill::copy_to_uninit_slice(this, mu_polyfill::as_bytes_mut(&mut t))
+        .unwrap_or_default();
+
+    T::read_from_bytes(bytes).map_err(|_| Error::size::<T>(mem::size_of::<T>(), available))
+}
+
+pub(crate) fn read_checked<T: TryFromBytes>(this: &mut impl Buf) -> Result<T, Error> {
+    let available = this.remaining();
+    if available < mem::size_of::<T>() {
+        return Err(Error::size::<T>(mem::size_of::<T>(), available));
+    }
+
+    // validate in place if possible, so that invalid data is not consumed.
+    if let Some(bytes) = this.chunk().get(..mem::size_of::<T>()) {
+        let t = T::try_read_from_bytes(bytes).map_err(|_| Error::validity::<T>())?;
+        this.advance(mem::size_of::<T>());
+        return Ok(t);
+    }
+
+    // otherwise validate a copy of the visible chunks before advancing.
+    let mut t = mem::MaybeUninit::<T>::uninit();
+    if let Ok(bytes) =
+        buf_polyfill::peek_to_uninit_slice(this, 0, mu_polyfill::as_bytes_mut(&mut t))
+    {
+        let t = T::try_read_from_bytes(bytes).map_err(|_| Error::validity::<T>())?;
+        this.advance(mem::size_of::<T>());
+        return Ok(t);
+    }
+
+    // the bytes could only be validated once consumed.
+    Err(Error::fragmented::<T>(mem::size_of::<T>()))
+}
+
+pub(crate) fn read_elems<T: FromBytes>(this: &mut impl Buf, count: usize) -> Result<Vec<T>, Error> {
+    let available = this.remaining();
+    let size = match count.checked_mul(mem::size_of::<T>()) {
+        Some(size) if size <= available => size,
+        size => return Err(Error::size::<[T]>(size.unwrap_or(usize::MAX), available)),
+    };
+
+    let mut v = Vec::<T>::new();
+    v.try_reserve_exact(count)
+        .map_err(|_| Error::alloc::<[T]>(size))?;
+
+    let dst = mu_polyfill::slice_as_bytes_mut(&mut v.spare_capacity_mut()[..count]);
+    buf_polyfill::copy_to_uninit_slice(this, dst).ok_or(Error::size::<[T]>(size, available))?;
+
+    // SAFETY: all `count` elements have been initialised from bytes, and `T: FromBytes`.
+    unsafe { v.set_len(count) };
+    Ok(v)
+}
+
+pub(crate) fn read_into<T: FromBytes + ?Sized>(
+    this: &mut impl Buf,
+    t: &mut T,
+) -> Result<(), Error> {
+    let available = this.remaining();
+    // SAFETY: only initialised bytes are copied in, and any bytes are a valid `T: FromBytes`.
+    let dst = unsafe { mu_polyfill::as_uninit_bytes_mut(t) };
+    let size = dst.len();
+    buf_polyfill::copy_to_uninit_slice(this, dst).ok_or(Error::size::<T>(size, available))?;
+    Ok(())
+}
+
+pub(crate) fn read_boxed<T: FromBytes + KnownLayout<PointerMetadata = usize> + ?Sized>(
+    this: &mut impl Buf,
+    count: usize,
+) -> Result<Box<T>, Error> {
+    let available = this.remaining();
+    let size = match layout::size_of::<T>(count) {
+        Some(size) if size <= available => size,
+        size => return Err(Error::size::<T>(size.unwrap_or(usize::MAX), available)),
+    };
+
+    let mut t = T::new_box_zeroed_with_elems(count).map_err(|_| Error::alloc::<T>(size))?;
+    read_into(this, &mut *t)?;
+    Ok(t)
+}
+
+pub(crate) fn peek_read_at<T: FromBytes>(this: &impl Buf, offset: usize) -> Result<T, Error> {
+    let available = this.remaining().saturating_sub(offset);
+    if available < mem::size_of::<T>() {
+        return Err(Error::size::<T>(mem::size_of::<T>(), available).at(offset));
+    }
+
+    let mut t = mem::MaybeUninit::<T>::uninit();
+    let bytes = buf_polyfill::peek_to_uninit_slice(this, offset, mu_polyfill::as_bytes_mut(&mut t))
+        .map_err(|visible| {
+            Error::size::<T>(mem::size_of::<T>(), visible.saturating_sub(offset)).at(offset)
+        })?;
+
+    T::read_from_bytes(bytes).map_err(|_| Error::size::<T>(mem::size_of::<T>(), available))
+}
+
+pub(crate) fn read_quic_varint(this: &mut impl Buf) -> Result<QuicVarInt, Error> {
+    let available = this.remaining();
+    let first = peek_read_at::<u8>(this, 0).map_err(|_| Error::size::<QuicVarInt>(1, available))?;
+
+    let len = QuicVarInt::decoded_len(first);
+    if available < len {
+        return Err(Error::size::<QuicVarInt>(len, available));
+    }
+
+    let mut buf = [0; 8];
+    this.copy_to_slice(&mut buf[..len]);
+    Ok(QuicVarInt::decode(&buf[..len]))
+}
@@ -0,0 +1,73 @@
+//! An iterator over fixed-size records in a buffer.
+
+use core::{iter::FusedIterator, marker::PhantomData, mem};
+
+use zerocopy::{Immutable, KnownLayout, Ref, Unaligned};
+
+use crate::ZeroCopyBuf;
+
+/// An iterator over the `T` records in a buffer.
+///
+/// Created by [`ZeroCopyBuf::records`].
+///
+/// If the buffer length is not a multiple of the size of `T`,
+/// the trailing bytes are not yielded and can be accessed with [`Records::remainder`].
+pub struct Records<B: ZeroCopyBuf, T> {
+    buf: B,
+    remainder: Ref<B::Buf, [u8]>,
+    _record: PhantomData<fn() -> T>,
+}
+
+impl<B: ZeroCopyBuf, T: KnownLayout + Immutable + Unaligned> Records<B, T> {
+    pub(crate) fn new(mut buf: B) -> Self {
+        let size = mem::size_of::<T>();
+        assert!(size != 0, "records cannot be zero-sized");
+
+        let remainder = buf
+            .try_get_suffix_elems::<[u8]>(buf.remaining() % size)
+            .expect("the remainder is always in bounds");
+
+        Self {
+            buf,
+            remainder,
+            _record: PhantomData,
+        }
+    }
+
+    /// The trailing bytes that do not fit in a whole record.
+    pub fn remainder(&self) -> &[u8] {
+        &self.remainder
+    }
+
+    /// Consumes the iterator, returning the trailing bytes that do not fit in a whole record.
+    pub fn into_remainder(self) -> Ref<B::Buf, [u8]> {
+        self.remainder
+    }
+}
+
+impl<B: ZeroCopyBuf, T: KnownLayout + Immutable + Unaligned> Iterator for Records<B, T> {
+    type Item = Ref<B::Buf, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.buf.try_get::<T>().ok()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<B: ZeroCopyBuf, T: KnownLayout + Immutable + Unaligned> DoubleEndedIterator for Records<B, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.buf.try_get_suffix::<T>().ok()
+    }
+}
+
+impl<B: ZeroCopyBuf, T: KnownLayout + Immutable + Unaligned> ExactSizeIterator for Records<B, T> {
+    fn len(&self) -> usize {
+        self.buf.remaining() / mem::size_of::<T>()
+    }
+}
+
+impl<B: ZeroCopyBuf, T: KnownLayout + Immutable + Unaligned> FusedIterator for Records<B, T> {}
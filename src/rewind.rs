@@ -0,0 +1,21 @@
+//! Buffers that can be restored after a failed parse.
+
+use bytes::Bytes;
+
+use crate::{Tracked, ZeroCopyBuf};
+
+/// A [`ZeroCopyBuf`] that is cheap to clone, because a clone shares the same bytes.
+///
+/// This is what lets [`ZeroCopyBuf::transaction`] and [`ZeroCopyBuf::parse_streaming`]
+/// keep a copy of the buffer to restore if the parse fails.
+///
+/// [`BytesMut`](bytes::BytesMut) uniquely owns its bytes, so cloning it copies them.
+/// To parse from a `BytesMut` receive buffer, parse a `&[u8]` view of it instead,
+/// and advance the receive buffer once the parse is [`Parsed::Complete`](crate::Parsed::Complete).
+pub trait Rewind: ZeroCopyBuf + Clone {}
+
+impl Rewind for Bytes {}
+
+impl Rewind for &[u8] {}
+
+impl<B: Rewind> Rewind for Tracked<B> {}
@@ -0,0 +1,173 @@
+//! Random access into buffers without consuming them.
+
+use bytes::{Bytes, BytesMut};
+use core::ops::DerefMut;
+use zerocopy::{Immutable, KnownLayout, Ref, SplitByteSlice, Unaligned};
+
+use crate::{layout, ByteSlice, Error, Res};
+
+/// A buffer that allows getting arbitrary values at any offset, without consuming them.
+///
+/// This is useful for formats with offset tables, such as ELF section headers or TIFF IFDs.
+pub trait ZeroCopySlice {
+    /// The buffer to borrow over. This is [`ByteSlice<Bytes>`] for [`Bytes`],
+    /// so the values can outlive the borrow of the buffer.
+    type Slice<'a>: SplitByteSlice
+    where
+        Self: 'a;
+
+    /// Get a ref to a `T` at `offset` bytes into the buffer.
+    ///
+    /// If the buffer does not contain a `T` at `offset`, an [`Error::Size`] is returned.
+    ///
+    /// ```
+    /// use zerocopy_buf::ZeroCopySlice;
+    ///
+    /// let data = bytes::Bytes::from_static(b"\x00\x00\x01\x02");
+    /// let x = data.get_at::<zerocopy::network_endian::U16>(2).unwrap();
+    /// drop(data);
+    ///
+    /// assert_eq!(x.get(), 0x0102);
+    /// ```
+    fn get_at<T: KnownLayout + Immutable + Unaligned>(
+        &self,
+        offset: usize,
+    ) -> Res<Self::Slice<'_>, T>;
+
+    /// Get a ref to a DST `T` with `count` elements at `offset` bytes into the buffer.
+    ///
+    /// If the buffer does not contain a `T` at `offset`, an [`Error::Size`] is returned.
+    fn get_elems_at<T: KnownLayout<PointerMetadata = usize> + Immutable + Unaligned + ?Sized>(
+        &self,
+        offset: usize,
+        count: usize,
+    ) -> Res<Self::Slice<'_>, T>;
+
+    /// Get a mutable ref to a `T` at `offset` bytes into the buffer.
+    ///
+    /// If the buffer does not contain a `T` at `offset`, an [`Error::Size`] is returned.
+    ///
+    /// ```
+    /// use zerocopy_buf::ZeroCopySlice;
+    ///
+    /// let mut data = bytes::BytesMut::from(&b"\x00\x00\x01\x02"[..]);
+    /// data.get_mut_at::<zerocopy::network_endian::U16>(2).unwrap().set(0x0304);
+    ///
+    /// assert_eq!(data, b"\x00\x00\x03\x04"[..]);
+    /// ```
+    fn get_mut_at<T: KnownLayout + Immutable + Unaligned>(
+        &mut self,
+        offset: usize,
+    ) -> Res<&mut [u8], T>
+    where
+        Self: DerefMut<Target = [u8]>,
+    {
+        let (r, _) = Ref::from_prefix(split_at::<_, T>(&mut **self, offset)?)
+            .map_err(|e| Error::from(e).at(offset))?;
+        Ok(r)
+    }
+
+    /// Get a mutable ref to a DST `T` with `count` elements at `offset` bytes into the buffer.
+    ///
+    /// If the buffer does not contain a `T` at `offset`, an [`Error::Size`] is returned.
+    fn get_elems_mut_at<T: KnownLayout<PointerMetadata = usize> + Immutable + Unaligned + ?Sized>(
+        &mut self,
+        offset: usize,
+        count: usize,
+    ) -> Res<&mut [u8], T>
+    where
+        Self: DerefMut<Target = [u8]>,
+    {
+        let bytes = split_at::<_, T>(&mut **self, offset).map_err(|e| e.with_elems::<T>(count))?;
+        let (r, _) = Ref::from_prefix_with_elems(bytes, count)
+            .map_err(|e| Error::from(e).with_elems::<T>(count).at(offset))?;
+        Ok(r)
+    }
+}
+
+/// Skip the first `offset` bytes of `source`.
+fn split_at<B: SplitByteSlice, T: KnownLayout + ?Sized>(
+    source: B,
+    offset: usize,
+) -> Result<B, Error> {
+    match source.split_at(offset) {
+        Ok((_, suffix)) => Ok(suffix),
+        Err(_) => Err(Error::size::<T>(layout::min_size_of::<T>(), 0).at(offset)),
+    }
+}
+
+impl ZeroCopySlice for Bytes {
+    type Slice<'a> = ByteSlice<Bytes>;
+
+    fn get_at<T: KnownLayout + Immutable + Unaligned>(
+        &self,
+        offset: usize,
+    ) -> Res<Self::Slice<'_>, T> {
+        let (r, _) = Ref::from_prefix(split_at::<_, T>(ByteSlice(self.clone()), offset)?)
+            .map_err(|e| Error::from(e).at(offset))?;
+        Ok(r)
+    }
+
+    fn get_elems_at<T: KnownLayout<PointerMetadata = usize> + Immutable + Unaligned + ?Sized>(
+        &self,
+        offset: usize,
+        count: usize,
+    ) -> Res<Self::Slice<'_>, T> {
+        let bytes = split_at::<_, T>(ByteSlice(self.clone()), offset)
+            .map_err(|e| e.with_elems::<T>(count))?;
+        let (r, _) = Ref::from_prefix_with_elems(bytes, count)
+            .map_err(|e| Error::from(e).with_elems::<T>(count).at(offset))?;
+        Ok(r)
+    }
+}
+
+impl ZeroCopySlice for BytesMut {
+    type Slice<'a> = &'a [u8];
+
+    fn get_at<T: KnownLayout + Immutable + Unaligned>(
+        &self,
+        offset: usize,
+    ) -> Res<Self::Slice<'_>, T> {
+        let (r, _) = Ref::from_prefix(split_at::<_, T>(&**self, offset)?)
+            .map_err(|e| Error::from(e).at(offset))?;
+        Ok(r)
+    }
+
+    fn get_elems_at<T: KnownLayout<PointerMetadata = usize> + Immutable + Unaligned + ?Sized>(
+        &self,
+        offset: usize,
+        count: usize,
+    ) -> Res<Self::Slice<'_>, T> {
+        let bytes = split_at::<_, T>(&**self, offset).map_err(|e| e.with_elems::<T>(count))?;
+        let (r, _) = Ref::from_prefix_with_elems(bytes, count)
+            .map_err(|e| Error::from(e).with_elems::<T>(count).at(offset))?;
+        Ok(r)
+    }
+}
+
+impl<'b> ZeroCopySlice for &'b [u8] {
+    type Slice<'a>
+        = &'b [u8]
+    where
+        Self: 'a;
+
+    fn get_at<T: KnownLayout + Immutable + Unaligned>(
+        &self,
+        offset: usize,
+    ) -> Res<Self::Slice<'_>, T> {
+        let (r, _) = Ref::from_prefix(split_at::<_, T>(*self, offset)?)
+            .map_err(|e| Error::from(e).at(offset))?;
+        Ok(r)
+    }
+
+    fn get_elems_at<T: KnownLayout<PointerMetadata = usize> + Immutable + Unaligned + ?Sized>(
+        &self,
+        offset: usize,
+        count: usize,
+    ) -> Res<Self::Slice<'_>, T> {
+        let bytes = split_at::<_, T>(*self, offset).map_err(|e| e.with_elems::<T>(count))?;
+        let (r, _) = Ref::from_prefix_with_elems(bytes, count)
+            .map_err(|e| Error::from(e).with_elems::<T>(count).at(offset))?;
+        Ok(r)
+    }
+}
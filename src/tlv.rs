@@ -0,0 +1,205 @@
+//! An iterator over type-length-value encoded entries.
+
+use core::{
+    marker::PhantomData,
+    mem,
+    ops::{Deref, Range},
+};
+
+use bytes::Bytes;
+use zerocopy::{FromBytes, Immutable, KnownLayout, Unaligned};
+
+use crate::{Error, Length, ZeroCopyBuf};
+
+/// An iterator over type-length-value entries, each starting with a `Tag` followed by a `Len`.
+///
+/// Each entry is yielded as the tag and the value. For [`Bytes`] the values share
+/// the original allocation.
+///
+/// If an entry is truncated or has an invalid length, an [`Error`] is yielded and iteration stops,
+/// leaving the invalid entry in [`Tlv::remainder`].
+///
+/// ```
+/// use bytes::Bytes;
+/// use zerocopy_buf::Tlv;
+///
+/// // DHCP options: subnet mask, router, end
+/// let data = Bytes::from_static(b"\x01\x04\xff\xff\xff\x00\x03\x04\xc0\xa8\x00\x01\xff\x00\x00");
+/// let mut options = Tlv::<_, u8, u8>::new(data).end_tag(255);
+///
+/// let (tag, value) = options.next().unwrap().unwrap();
+/// assert_eq!(tag, 1);
+/// assert_eq!(value, b"\xff\xff\xff\x00"[..]);
+///
+/// let (tag, value) = options.next().unwrap().unwrap();
+/// assert_eq!(tag, 3);
+/// assert_eq!(value, b"\xc0\xa8\x00\x01"[..]);
+///
+/// assert!(options.next().is_none());
+/// assert_eq!(options.remainder(), b"\x00\x00");
+/// ```
+pub struct Tlv<B, Tag, Len> {
+    buf: B,
+    length_includes_header: bool,
+    align: usize,
+    end_tag: Option<Tag>,
+    offset: usize,
+    done: bool,
+    _len: PhantomData<fn() -> Len>,
+}
+
+impl<B, Tag, Len> Tlv<B, Tag, Len>
+where
+    B: ZeroCopyBuf + Deref<Target = [u8]>,
+    Tag: FromBytes + KnownLayout + Immutable + Unaligned + Copy + PartialEq,
+    Len: Length,
+{
+    /// Iterate over the entries in `buf`.
+    pub fn new(buf: B) -> Self {
+        Self {
+            buf,
+            length_includes_header: false,
+            align: 1,
+            end_tag: None,
+            offset: 0,
+            done: false,
+            _len: PhantomData,
+        }
+    }
+
+    /// Whether the length of an entry includes the size of the tag and length fields.
+    ///
+    /// Defaults to `false`.
+    pub fn length_includes_header(mut self, yes: bool) -> Self {
+        self.length_includes_header = yes;
+        self
+    }
+
+    /// Each entry is padded to a multiple of `align` bytes. The padding is not included in the length.
+    ///
+    /// Defaults to `1`, no padding.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `align` is zero.
+    pub fn align(mut self, align: usize) -> Self {
+        assert!(align != 0, "alignment must be non-zero");
+        self.align = align;
+        self
+    }
+
+    /// A tag that marks the end of the entries. The end tag has no length or value.
+    pub fn end_tag(mut self, tag: Tag) -> Self {
+        self.end_tag = Some(tag);
+        self
+    }
+
+    /// The number of bytes consumed by the entries yielded so far.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The bytes that have not been consumed.
+    ///
+    /// After an end tag, this is everything following it.
+    pub fn remainder(&self) -> &[u8] {
+        &self.buf
+    }
+
+    /// Consumes the iterator, returning the bytes that have not been consumed.
+    pub fn into_inner(self) -> B {
+        self.buf
+    }
+
+    /// Parse the next entry, returning the tag and the range of the value in the buffer.
+    fn next_entry(&mut self) -> Option<Result<(Tag, Range<usize>), Error>> {
+        if self.done || self.buf.is_empty() {
+            return None;
+        }
+        match self.parse_entry() {
+            Ok(Some(entry)) => Some(Ok(entry)),
+            Ok(None) => {
+                self.done = true;
+                self.consume(mem::size_of::<Tag>());
+                None
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e.at(self.offset)))
+            }
+        }
+    }
+
+    /// Parse the entry at the start of the buffer, or `None` if it is the end tag.
+    fn parse_entry(&self) -> Result<Option<(Tag, Range<usize>)>, Error> {
+        let mut rest: &[u8] = &self.buf;
+
+        let tag = *rest.try_get::<Tag>()?;
+        if self.end_tag == Some(tag) {
+            return Ok(None);
+        }
+
+        let len = rest
+            .try_get::<Len>()
+            .map_err(|e| e.at(mem::size_of::<Tag>()))?
+            .to_usize();
+
+        let header = mem::size_of::<Tag>() + mem::size_of::<Len>();
+        let len = if self.length_includes_header {
+            len.checked_sub(header)
+                .ok_or(Error::validity::<Len>().at(mem::size_of::<Tag>()))?
+        } else {
+            len
+        };
+
+        if len > rest.len() {
+            return Err(Error::size::<[u8]>(len, rest.len()).at(header));
+        }
+        Ok(Some((tag, header..header + len)))
+    }
+
+    /// Advance past an entry of `size` bytes, including its padding.
+    fn consume(&mut self, size: usize) {
+        let padded = size.next_multiple_of(self.align);
+        let n = padded.min(self.buf.remaining());
+        self.buf.advance(n);
+        self.offset += n;
+    }
+}
+
+impl<Tag, Len> Iterator for Tlv<Bytes, Tag, Len>
+where
+    Tag: FromBytes + KnownLayout + Immutable + Unaligned + Copy + PartialEq,
+    Len: Length,
+{
+    type Item = Result<(Tag, Bytes), Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (tag, range) = match self.next_entry()? {
+            Ok(entry) => entry,
+            Err(e) => return Some(Err(e)),
+        };
+        let value = self.buf.slice(range.clone());
+        self.consume(range.end);
+        Some(Ok((tag, value)))
+    }
+}
+
+impl<'a, Tag, Len> Iterator for Tlv<&'a [u8], Tag, Len>
+where
+    Tag: FromBytes + KnownLayout + Immutable + Unaligned + Copy + PartialEq,
+    Len: Length,
+{
+    type Item = Result<(Tag, &'a [u8]), Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (tag, range) = match self.next_entry()? {
+            Ok(entry) => entry,
+            Err(e) => return Some(Err(e)),
+        };
+        let buf: &'a [u8] = self.buf;
+        let value = &buf[range.clone()];
+        self.consume(range.end);
+        Some(Ok((tag, value)))
+    }
+}
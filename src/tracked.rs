@@ -0,0 +1,384 @@
+//! A buffer wrapper that tracks the offset into the message.
+
+use alloc::vec::Vec;
+use core::any::type_name;
+
+use bytes::Buf;
+use zerocopy::{FromBytes, Immutable, KnownLayout, Ref, TryFromBytes, Unaligned};
+
+use crate::{cstr, CStrBuf, Error, Length, Res, TryRef, TryRes, ZeroCopyBuf, ZeroCopyReadBuf};
+
+/// A [`Buf`] that keeps track of how many bytes have been consumed from it.
+///
+/// Errors returned by the [`ZeroCopyBuf`] and [`ZeroCopyReadBuf`] implementations
+/// of a `Tracked` buffer report the absolute offset into the message.
+///
+/// In trace mode, every read is also logged as a [`TraceEntry`],
+/// so that the decoding of a malformed message can be replayed.
+///
+/// ```
+/// use zerocopy_buf::{Tracked, ZeroCopyBuf};
+/// use zerocopy::network_endian::{U16, U32};
+///
+/// let mut data = Tracked::with_trace(&b"\x00\x01\x00\x00\x00"[..]);
+/// data.try_get::<U16>().unwrap();
+///
+/// let err = data.try_get::<U32>().unwrap_err();
+/// assert_eq!(err.offset(), 2);
+///
+/// assert_eq!(data.trace()[0].offset, 0);
+/// assert_eq!(data.trace()[0].size, 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Tracked<B> {
+    inner: B,
+    offset: usize,
+    trace: Option<Vec<TraceEntry>>,
+}
+
+/// A read from a [`Tracked`] buffer in trace mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraceEntry {
+    /// The offset the value was read from.
+    pub offset: usize,
+    /// The name of the type that was read.
+    ///
+    /// Bytes skipped with [`Buf::advance`] are recorded as `[u8]`.
+    pub type_name: &'static str,
+    /// The number of bytes that were read.
+    pub size: usize,
+}
+
+impl<B> Tracked<B> {
+    /// Start tracking the buffer at offset 0.
+    pub fn new(inner: B) -> Self {
+        Self {
+            inner,
+            offset: 0,
+            trace: None,
+        }
+    }
+
+    /// Start tracking the buffer at offset 0, recording a trace of all reads.
+    pub fn with_trace(inner: B) -> Self {
+        Self {
+            inner,
+            offset: 0,
+            trace: Some(Vec::new()),
+        }
+    }
+
+    /// The number of bytes consumed so far.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// All reads recorded so far. This is always empty unless created with [`Tracked::with_trace`].
+    pub fn trace(&self) -> &[TraceEntry] {
+        self.trace.as_deref().unwrap_or_default()
+    }
+
+    /// Gets a reference to the underlying buffer.
+    pub fn get_ref(&self) -> &B {
+        &self.inner
+    }
+
+    /// Consumes this `Tracked`, returning the underlying buffer.
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+
+    fn record<T: ?Sized>(&mut self, size: usize) {
+        self.record_at::<T>(self.offset, size);
+        self.offset += size;
+    }
+
+    fn record_at<T: ?Sized>(&mut self, offset: usize, size: usize) {
+        if let Some(trace) = &mut self.trace {
+            trace.push(TraceEntry {
+                offset,
+                type_name: type_name::<T>(),
+                size,
+            });
+        }
+    }
+}
+
+impl<B: Buf> ZeroCopyReadBuf for Tracked<B> {
+    fn read_offset(&self) -> usize {
+        self.offset
+    }
+
+    fn record_read<T: ?Sized>(&mut self, size: usize) {
+        // the bytes were recorded as `[u8]` when they were advanced over,
+        // so those entries are replaced with one for the `T`.
+        if let Some(trace) = &mut self.trace {
+            let mut skipped = 0;
+            while skipped < size {
+                match trace.pop() {
+                    Some(entry) => skipped += entry.size,
+                    None => break,
+                }
+            }
+        }
+        self.record_at::<T>(self.offset - size, size);
+    }
+}
+
+impl<B: Buf> Buf for Tracked<B> {
+    fn remaining(&self) -> usize {
+        self.inner.remaining()
+    }
+
+    fn chunk(&self) -> &[u8] {
+        self.inner.chunk()
+    }
+
+    #[cfg(feature = "std")]
+    fn chunks_vectored<'a>(&'a self, dst: &mut [std::io::IoSlice<'a>]) -> usize {
+        self.inner.chunks_vectored(dst)
+    }
+
+    fn advance(&mut self, cnt: usize) {
+        self.inner.advance(cnt);
+        self.record::<[u8]>(cnt);
+    }
+}
+
+impl<B: ZeroCopyBuf> ZeroCopyBuf for Tracked<B> {
+    type Buf = B::Buf;
+
+    fn try_get<T: KnownLayout + Immutable + Unaligned>(&mut self) -> Res<Self::Buf, T> {
+        let r = self.inner.try_get::<T>().map_err(|e| e.at(self.offset))?;
+        self.record::<T>(Ref::bytes(&r).len());
+        Ok(r)
+    }
+
+    fn try_get_elems<T: KnownLayout<PointerMetadata = usize> + Immutable + Unaligned + ?Sized>(
+        &mut self,
+        count: usize,
+    ) -> Res<Self::Buf, T> {
+        let r = self
+            .inner
+            .try_get_elems::<T>(count)
+            .map_err(|e| e.at(self.offset))?;
+        self.record::<T>(Ref::bytes(&r).len());
+        Ok(r)
+    }
+
+    fn try_peek<T: KnownLayout + Immutable + Unaligned>(&self) -> Res<&[u8], T> {
+        let offset = self.offset;
+        self.inner.try_peek::<T>().map_err(|e| e.at(offset))
+    }
+
+    fn try_peek_elems<T: KnownLayout<PointerMetadata = usize> + Immutable + Unaligned + ?Sized>(
+        &self,
+        count: usize,
+    ) -> Res<&[u8], T> {
+        let offset = self.offset;
+        self.inner
+            .try_peek_elems::<T>(count)
+            .map_err(|e| e.at(offset))
+    }
+
+    /// Same as the provided method, but reports the absolute offset in errors.
+    fn try_get_with_len<H, T>(&mut self, len: impl FnOnce(&H) -> usize) -> Res<Self::Buf, T>
+    where
+        H: FromBytes + KnownLayout + Immutable + Unaligned,
+        T: KnownLayout<PointerMetadata = usize> + Immutable + Unaligned + ?Sized,
+    {
+        let r = self
+            .inner
+            .try_get_with_len::<H, T>(len)
+            .map_err(|e| e.at(self.offset))?;
+        self.record::<T>(Ref::bytes(&r).len());
+        Ok(r)
+    }
+
+    /// Same as the provided method, but reports the absolute offset in errors.
+    ///
+    /// The section starts after the prefix, and has its own trace if this buffer is in trace mode.
+    fn try_get_prefixed<L: Length>(&mut self) -> Result<Self, Error>
+    where
+        Self: Sized,
+    {
+        let inner = self
+            .inner
+            .try_get_prefixed::<L>()
+            .map_err(|e| e.at(self.offset))?;
+        self.record::<L>(core::mem::size_of::<L>());
+        let section = Tracked {
+            inner,
+            offset: self.offset,
+            trace: self.trace.as_ref().map(|_| Vec::new()),
+        };
+        self.record::<[u8]>(section.inner.remaining());
+        Ok(section)
+    }
+
+    /// Same as the provided method, but reports the absolute offset in errors.
+    fn try_get_prefixed_elems<L: Length, T: KnownLayout + Immutable + Unaligned>(
+        &mut self,
+    ) -> Res<Self::Buf, [T]> {
+        let r = self
+            .inner
+            .try_get_prefixed_elems::<L, T>()
+            .map_err(|e| e.at(self.offset))?;
+        self.record::<L>(core::mem::size_of::<L>());
+        self.record::<[T]>(Ref::bytes(&r).len());
+        Ok(r)
+    }
+
+    fn try_get_checked<T: TryFromBytes + KnownLayout + Immutable + Unaligned>(
+        &mut self,
+    ) -> TryRes<Self::Buf, T> {
+        let r = self
+            .inner
+            .try_get_checked::<T>()
+            .map_err(|e| e.at(self.offset))?;
+        self.record::<T>(TryRef::bytes(&r).len());
+        Ok(r)
+    }
+
+    fn try_get_elems_checked<
+        T: TryFromBytes + KnownLayout<PointerMetadata = usize> + Immutable + Unaligned + ?Sized,
+    >(
+        &mut self,
+        count: usize,
+    ) -> TryRes<Self::Buf, T> {
+        let r = self
+            .inner
+            .try_get_elems_checked::<T>(count)
+            .map_err(|e| e.at(self.offset))?;
+        self.record::<T>(TryRef::bytes(&r).len());
+        Ok(r)
+    }
+
+    fn try_peek_checked<T: TryFromBytes + KnownLayout + Immutable + Unaligned>(
+        &self,
+    ) -> TryRes<&[u8], T> {
+        let offset = self.offset;
+        self.inner.try_peek_checked::<T>().map_err(|e| e.at(offset))
+    }
+
+    fn try_peek_elems_checked<
+        T: TryFromBytes + KnownLayout<PointerMetadata = usize> + Immutable + Unaligned + ?Sized,
+    >(
+        &self,
+        count: usize,
+    ) -> TryRes<&[u8], T> {
+        let offset = self.offset;
+        self.inner
+            .try_peek_elems_checked::<T>(count)
+            .map_err(|e| e.at(offset))
+    }
+
+    fn try_get_aligned<T: KnownLayout + Immutable>(&mut self) -> Res<Self::Buf, T> {
+        let r = self
+            .inner
+            .try_get_aligned::<T>()
+            .map_err(|e| e.at(self.offset))?;
+        self.record::<T>(Ref::bytes(&r).len());
+        Ok(r)
+    }
+
+    fn try_get_elems_aligned<T: KnownLayout<PointerMetadata = usize> + Immutable + ?Sized>(
+        &mut self,
+        count: usize,
+    ) -> Res<Self::Buf, T> {
+        let r = self
+            .inner
+            .try_get_elems_aligned::<T>(count)
+            .map_err(|e| e.at(self.offset))?;
+        self.record::<T>(Ref::bytes(&r).len());
+        Ok(r)
+    }
+
+    fn try_peek_aligned<T: KnownLayout + Immutable>(&self) -> Res<&[u8], T> {
+        let offset = self.offset;
+        self.inner.try_peek_aligned::<T>().map_err(|e| e.at(offset))
+    }
+
+    fn try_peek_elems_aligned<T: KnownLayout<PointerMetadata = usize> + Immutable + ?Sized>(
+        &self,
+        count: usize,
+    ) -> Res<&[u8], T> {
+        let offset = self.offset;
+        self.inner
+            .try_peek_elems_aligned::<T>(count)
+            .map_err(|e| e.at(offset))
+    }
+
+    fn try_get_suffix<T: KnownLayout + Immutable + Unaligned>(&mut self) -> Res<Self::Buf, T> {
+        let r = self
+            .inner
+            .try_get_suffix::<T>()
+            .map_err(|e| e.at(self.offset))?;
+        self.record_at::<T>(self.offset + self.inner.remaining(), Ref::bytes(&r).len());
+        Ok(r)
+    }
+
+    fn try_get_suffix_elems<
+        T: KnownLayout<PointerMetadata = usize> + Immutable + Unaligned + ?Sized,
+    >(
+        &mut self,
+        count: usize,
+    ) -> Res<Self::Buf, T> {
+        let r = self
+            .inner
+            .try_get_suffix_elems::<T>(count)
+            .map_err(|e| e.at(self.offset))?;
+        self.record_at::<T>(self.offset + self.inner.remaining(), Ref::bytes(&r).len());
+        Ok(r)
+    }
+
+    fn try_peek_suffix<T: KnownLayout + Immutable + Unaligned>(&self) -> Res<&[u8], T> {
+        let offset = self.offset;
+        self.inner.try_peek_suffix::<T>().map_err(|e| e.at(offset))
+    }
+
+    /// The section starts at the current offset, and has its own trace if this buffer is in trace mode.
+    fn try_split_section(&mut self, len: usize) -> Result<Self, Error> {
+        let inner = self
+            .inner
+            .try_split_section(len)
+            .map_err(|e| e.at(self.offset))?;
+        let section = Tracked {
+            inner,
+            offset: self.offset,
+            trace: self.trace.as_ref().map(|_| Vec::new()),
+        };
+        self.record::<[u8]>(len);
+        Ok(section)
+    }
+
+    /// Same as the provided method, but reports the absolute offset in errors.
+    fn try_get_cstr(&mut self) -> Result<CStrBuf<Self>, Error> {
+        let offset = self.offset;
+        cstr::get(self, None).map_err(|e| e.at(offset))
+    }
+
+    /// Same as the provided method, but reports the absolute offset in errors.
+    fn try_get_cstr_in(&mut self, width: usize) -> Result<CStrBuf<Self>, Error> {
+        let offset = self.offset;
+        cstr::get(self, Some(width)).map_err(|e| e.at(offset))
+    }
+
+    /// Same as the provided method, but the section's trace is appended to this buffer's trace.
+    fn with_section<R>(
+        &mut self,
+        len: usize,
+        f: impl FnOnce(&mut Self) -> Result<R, Error>,
+    ) -> Result<R, Error> {
+        let mut section = self.try_split_section(len)?;
+        let res = f(&mut section);
+        if let (Some(trace), Some(inner)) = (&mut self.trace, section.trace) {
+            trace.extend(inner);
+        }
+        let r = res.map_err(Error::overrun)?;
+        match section.inner.remaining() {
+            0 => Ok(r),
+            n => Err(Error::leftover::<[u8]>(n).at(section.offset)),
+        }
+    }
+}
@@ -0,0 +1,114 @@
+//! A validated counterpart of [`zerocopy::Ref`] for [`TryFromBytes`] types.
+
+use core::{fmt, mem, ops::Deref, ptr::NonNull};
+
+use zerocopy::{
+    ByteSlice, CloneableByteSlice, Immutable, KnownLayout, SplitByteSlice, TryCastError,
+    TryFromBytes,
+};
+
+/// A typed reference derived from a byte slice whose contents have been
+/// validated as a `T`.
+///
+/// [`zerocopy::Ref`] can only be dereferenced when `T: FromBytes`.
+/// `TryRef` is returned by the `*_checked` methods of [`ZeroCopyBuf`](crate::ZeroCopyBuf)
+/// and dereferences to any [`TryFromBytes`] type whose bit pattern has already been checked.
+pub struct TryRef<B, T: ?Sized> {
+    bytes: B,
+    /// Points into `bytes`, which has been validated as a `T`.
+    ptr: NonNull<T>,
+}
+
+// SAFETY: `TryRef` only hands out shared references to `T`.
+unsafe impl<B: Send, T: ?Sized + Sync> Send for TryRef<B, T> {}
+
+// SAFETY: `TryRef` only hands out shared references to `T`.
+unsafe impl<B: Sync, T: ?Sized + Sync> Sync for TryRef<B, T> {}
+
+impl<B, T> TryRef<B, T>
+where
+    B: SplitByteSlice,
+    T: TryFromBytes + KnownLayout + Immutable + ?Sized,
+{
+    /// Like [`zerocopy::Ref::from_prefix`], but validates the bytes of `T`.
+    pub(crate) fn try_from_prefix(source: B) -> Result<(Self, B), TryCastError<B, T>> {
+        let ptr = match T::try_ref_from_prefix(&source) {
+            Ok((t, _)) => NonNull::from(t),
+            Err(e) => {
+                let e = e.map_src(|_| ());
+                return Err(e.map_src(|()| source));
+            }
+        };
+
+        // SAFETY: `ptr` was derived from a prefix of `source`.
+        Ok(unsafe { Self::split_at_ptr(source, ptr) })
+    }
+
+    /// Like [`zerocopy::Ref::from_prefix_with_elems`], but validates the bytes of `T`.
+    pub(crate) fn try_from_prefix_with_elems(
+        source: B,
+        count: usize,
+    ) -> Result<(Self, B), TryCastError<B, T>>
+    where
+        T: KnownLayout<PointerMetadata = usize>,
+    {
+        let ptr = match T::try_ref_from_prefix_with_elems(&source, count) {
+            Ok((t, _)) => NonNull::from(t),
+            Err(e) => {
+                let e = e.map_src(|_| ());
+                return Err(e.map_src(|()| source));
+            }
+        };
+
+        // SAFETY: `ptr` was derived from a prefix of `source`.
+        Ok(unsafe { Self::split_at_ptr(source, ptr) })
+    }
+
+    /// # Safety
+    /// `ptr` must point to a valid `T` that starts at the beginning of `source`.
+    unsafe fn split_at_ptr(source: B, ptr: NonNull<T>) -> (Self, B) {
+        // SAFETY: `ptr` is valid for reads according to the caller.
+        let len = mem::size_of_val(unsafe { ptr.as_ref() });
+
+        // SAFETY: `T` is a prefix of `source`, so `len <= source.len()`.
+        // The contract of `SplitByteSlice` guarantees that the prefix covers
+        // the same bytes as before, so `ptr` stays valid for `bytes`.
+        let (bytes, suffix) = unsafe { source.split_at_unchecked(len) };
+        (Self { bytes, ptr }, suffix)
+    }
+}
+
+impl<B: ByteSlice, T: ?Sized> TryRef<B, T> {
+    /// Gets the underlying bytes.
+    pub fn bytes(r: &Self) -> &[u8] {
+        &r.bytes
+    }
+}
+
+impl<B: ByteSlice, T: ?Sized> Deref for TryRef<B, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: `ptr` was validated as a `T` when this `TryRef` was constructed.
+        // `ByteSlice` guarantees that the bytes do not move, and they are never
+        // mutated because `TryRef` only hands out shared references.
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+impl<B: CloneableByteSlice, T: ?Sized> Clone for TryRef<B, T> {
+    fn clone(&self) -> Self {
+        // `CloneableByteSlice` guarantees that the clone has the same address,
+        // so `ptr` is still valid.
+        Self {
+            bytes: self.bytes.clone(),
+            ptr: self.ptr,
+        }
+    }
+}
+
+impl<B: ByteSlice, T: ?Sized + fmt::Debug> fmt::Debug for TryRef<B, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TryRef").field(&&**self).finish()
+    }
+}
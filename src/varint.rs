@@ -0,0 +1,108 @@
+//! LEB128 variable length integers, as used by protobuf, DWARF and WebAssembly.
+
+use core::mem;
+
+use bytes::{Buf, BufMut};
+
+use crate::{buf_polyfill, Error};
+
+/// The longest encoding of a `u64`.
+const MAX_LEN: usize = 10;
+
+/// Decodes a varint one byte at a time.
+#[derive(Default)]
+struct Decoder {
+    value: u64,
+    len: usize,
+}
+
+impl Decoder {
+    /// Feed the next byte, returning the value once the last byte is seen.
+    ///
+    /// Returns an error if the encoding is overlong or does not fit in a `u64`.
+    fn push(&mut self, byte: u8) -> Result<Option<u64>, ()> {
+        let shift = self.len * 7;
+        self.len += 1;
+
+        // the 10th byte only has room for the top bit of a u64.
+        if shift == 63 && byte > 1 {
+            return Err(());
+        }
+        self.value |= u64::from(byte & 0x7f) << shift;
+
+        match byte {
+            0x80.. => Ok(None),
+            0 if self.len > 1 => Err(()),
+            _ => Ok(Some(self.value)),
+        }
+    }
+}
+
+/// Read a varint as a `T`, using `convert` to narrow the decoded value.
+pub(crate) fn read<T>(this: &mut impl Buf, convert: impl Fn(u64) -> Option<T>) -> Result<T, Error> {
+    let available = this.remaining();
+    let mut decoder = Decoder::default();
+
+    // decode a copy of the visible bytes first, so that invalid data is not consumed.
+    let mut window = [mem::MaybeUninit::uninit(); MAX_LEN];
+    let window = &mut window[..usize::min(MAX_LEN, available)];
+    let bytes = match buf_polyfill::peek_to_uninit_slice(this, 0, window) {
+        Ok(bytes) => bytes,
+        Err(visible) => {
+            buf_polyfill::peek_to_uninit_slice(this, 0, &mut window[..visible]).unwrap_or_default()
+        }
+    };
+    for &byte in &*bytes {
+        if let Some(value) = decoder.push(byte).map_err(|()| Error::validity::<T>())? {
+            let t = convert(value).ok_or_else(Error::validity::<T>)?;
+            this.advance(decoder.len);
+            return Ok(t);
+        }
+    }
+
+    if decoder.len == available {
+        return Err(Error::size::<T>(decoder.len + 1, available));
+    }
+
+    // the rest of the varint is not visible without advancing.
+    this.advance(decoder.len);
+    loop {
+        if !this.has_remaining() {
+            return Err(Error::size::<T>(decoder.len + 1, available));
+        }
+        if let Some(value) = decoder
+            .push(this.get_u8())
+            .map_err(|()| Error::validity::<T>())?
+        {
+            return convert(value).ok_or_else(Error::validity::<T>);
+        }
+    }
+}
+
+/// Write `value` as a varint.
+pub(crate) fn write(this: &mut (impl BufMut + ?Sized), mut value: u64) {
+    let mut buf = [0; MAX_LEN];
+    let mut len = 0;
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            buf[len] = byte;
+            len += 1;
+            break;
+        }
+        buf[len] = byte | 0x80;
+        len += 1;
+    }
+    this.put_slice(&buf[..len]);
+}
+
+/// Map signed integers to unsigned integers so that small magnitudes have short encodings.
+pub(crate) fn zigzag_encode(n: i64) -> u64 {
+    ((n << 1) ^ (n >> 63)) as u64
+}
+
+/// The inverse of [`zigzag_encode`].
+pub(crate) fn zigzag_decode(n: u64) -> i64 {
+    (n >> 1) as i64 ^ -((n & 1) as i64)
+}
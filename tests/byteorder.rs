@@ -0,0 +1,119 @@
+use zerocopy::{
+    byteorder::{BigEndian, LittleEndian, NetworkEndian},
+    FromBytes, Immutable, IntoBytes, KnownLayout, Unaligned,
+};
+use zerocopy_buf::{Error, Length, ZeroCopyBuf, I24, I48, U24, U48};
+
+#[derive(FromBytes, IntoBytes, KnownLayout, Immutable, Unaligned, Debug)]
+#[repr(C)]
+struct FrameHeader {
+    len: U24<NetworkEndian>,
+    kind: u8,
+    flags: u8,
+    stream: zerocopy::network_endian::U32,
+}
+
+#[test]
+fn http2_frame_header() {
+    let mut data: &[u8] = &b"\x00\x01\x02\x04\x01\x00\x00\x00\x03"[..];
+    let header = data.try_get::<FrameHeader>().unwrap();
+
+    assert_eq!(header.len.get(), 0x0102);
+    assert_eq!(header.len.to_usize(), 0x0102);
+    assert_eq!(header.kind, 4);
+    assert_eq!(header.stream.get(), 3);
+
+    let err = data.try_get::<U24<NetworkEndian>>().unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Size {
+            needed: 3,
+            available: 0,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn byte_order() {
+    let be = U24::<BigEndian>::new(0x010203).unwrap();
+    let le = U24::<LittleEndian>::new(0x010203).unwrap();
+    assert_eq!(be.as_bytes(), b"\x01\x02\x03");
+    assert_eq!(le.as_bytes(), b"\x03\x02\x01");
+
+    let be = U48::<BigEndian>::new(0x010203040506).unwrap();
+    let le = U48::<LittleEndian>::new(0x010203040506).unwrap();
+    assert_eq!(be.as_bytes(), b"\x01\x02\x03\x04\x05\x06");
+    assert_eq!(le.as_bytes(), b"\x06\x05\x04\x03\x02\x01");
+    assert_eq!(U48::<LittleEndian>::from_bytes(le.to_bytes()), le);
+}
+
+#[test]
+fn signed() {
+    let n = I24::<BigEndian>::new(-2).unwrap();
+    assert_eq!(n.as_bytes(), b"\xff\xff\xfe");
+    assert_eq!(n.get(), -2);
+    assert_eq!(i64::from(n), -2);
+
+    let n = I48::<LittleEndian>::new(I48::<LittleEndian>::MIN).unwrap();
+    assert_eq!(n.as_bytes(), b"\x00\x00\x00\x00\x00\x80");
+    assert_eq!(n.get(), -(1 << 47));
+}
+
+#[test]
+fn checked_construction() {
+    assert_eq!(U24::<BigEndian>::MAX, 0xffffff);
+    assert!(U24::<BigEndian>::new(0xffffff).is_some());
+    assert!(U24::<BigEndian>::new(0x1000000).is_none());
+    assert_eq!(U24::<BigEndian>::new_truncating(0x1000001).get(), 1);
+
+    assert_eq!(I24::<BigEndian>::MIN, -0x800000);
+    assert_eq!(I24::<BigEndian>::MAX, 0x7fffff);
+    assert!(I24::<BigEndian>::new(-0x800000).is_some());
+    assert!(I24::<BigEndian>::new(-0x800001).is_none());
+    assert!(I24::<BigEndian>::new(0x800000).is_none());
+
+    assert!(U48::<BigEndian>::new(1 << 48).is_none());
+    assert!(I48::<BigEndian>::new(1 << 47).is_none());
+
+    let mut n = U24::<LittleEndian>::from(7u16);
+    n.set(0xabcdef);
+    assert_eq!(u32::from(n), 0xabcdef);
+}
+
+#[test]
+#[should_panic]
+fn set_out_of_range() {
+    let mut n = U24::<BigEndian>::ZERO;
+    n.set(0x1000000);
+}
+
+#[test]
+fn arithmetic() {
+    let a = U24::<BigEndian>::from(1000u16);
+    let b = U24::<BigEndian>::from(24u8);
+
+    assert_eq!((a + b).get(), 1024);
+    assert_eq!((a - b).get(), 976);
+    assert_eq!((a * b).get(), 24000);
+    assert_eq!((a / b).get(), 41);
+    assert_eq!((a % b).get(), 16);
+    assert_eq!((a & b).get(), 1000 & 24);
+    assert_eq!((a | b).get(), 1000 | 24);
+    assert_eq!((a ^ b).get(), 1000 ^ 24);
+
+    let mut c = I24::<LittleEndian>::from(-5i8);
+    c *= I24::from(3i8);
+    c -= I24::from(1i8);
+    assert_eq!(c.get(), -16);
+    assert_eq!((c & I24::from(-1i8)).get(), -16);
+    assert!(c < I24::ZERO);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic = "attempt to add with overflow"]
+fn arithmetic_overflow() {
+    let max = U24::<BigEndian>::new(U24::<BigEndian>::MAX).unwrap();
+    let _ = max + U24::from(1u8);
+}
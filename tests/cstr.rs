@@ -0,0 +1,88 @@
+use bytes::{Bytes, BytesMut};
+use zerocopy_buf::{Error, Parsed, Tracked, ZeroCopyBuf};
+
+#[test]
+fn try_get_cstr() {
+    let mut data = Bytes::from_static(b"vmlinuz\0initrd\0\0");
+
+    let a = data.try_get_cstr().unwrap();
+    let b = data.try_get_cstr().unwrap();
+    let c = data.try_get_cstr().unwrap();
+    assert!(data.is_empty());
+
+    assert_eq!(a.to_bytes(), b"vmlinuz");
+    assert_eq!(a.as_cstr().unwrap(), c"vmlinuz");
+    assert_eq!(b.clone().into_inner(), b"initrd\0"[..]);
+    assert_eq!(b.into_bytes(), b"initrd"[..]);
+    assert_eq!(c.to_bytes(), b"");
+    assert_eq!(format!("{a:?}"), "\"vmlinuz\"");
+
+    let mut data = BytesMut::from(&b"a\0b"[..]);
+    let a: BytesMut = data.try_get_cstr().unwrap().into_bytes();
+    assert_eq!(a, b"a"[..]);
+    assert_eq!(data, b"b"[..]);
+}
+
+#[test]
+fn try_get_cstr_error() {
+    let mut data: &[u8] = &b"partial"[..];
+    let err = data.try_get_cstr().unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Size {
+            needed: 8,
+            available: 7,
+            ..
+        }
+    ));
+    assert_eq!(data.len(), 7);
+
+    let mut data = Bytes::from_static(b"part");
+    assert_eq!(
+        data.parse_streaming(|b| Ok(b.try_get_cstr()?.into_bytes())),
+        Parsed::Incomplete { needed: 1 }
+    );
+    let mut data = Bytes::from_static(b"partial\0");
+    assert_eq!(
+        data.parse_streaming(|b| Ok(b.try_get_cstr()?.into_bytes())),
+        Parsed::Complete(Bytes::from_static(b"partial"))
+    );
+}
+
+#[test]
+fn try_get_cstr_in() {
+    let mut data: &[u8] = &b"lo\0\0\0\0\0\0ethernet\xff"[..];
+
+    let a = data.try_get_cstr_in(8).unwrap();
+    assert_eq!(a.to_bytes(), b"lo");
+    assert_eq!(a.as_cstr().unwrap(), c"lo");
+    assert_eq!(a.into_inner().len(), 8);
+
+    let b = data.try_get_cstr_in(8).unwrap();
+    assert_eq!(b.to_bytes(), b"ethernet");
+    assert!(b.as_cstr().is_none());
+
+    let err = data.try_get_cstr_in(8).unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Size {
+            needed: 8,
+            available: 1,
+            ..
+        }
+    ));
+    assert_eq!(data, b"\xff");
+}
+
+#[test]
+fn tracked_cstr() {
+    let mut data = Tracked::new(&b"ab\0cd"[..]);
+    let s = data.try_get_cstr().unwrap();
+    assert_eq!(s.to_bytes(), b"ab");
+    assert_eq!(data.offset(), 3);
+
+    let err = data.try_get_cstr().unwrap_err();
+    assert_eq!(err.offset(), 3);
+    let err = data.try_get_cstr_in(4).unwrap_err();
+    assert_eq!(err.offset(), 3);
+}
@@ -0,0 +1,99 @@
+use bytes::{Bytes, BytesMut};
+use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout, Unaligned};
+use zerocopy_buf::{
+    DynEndian, Endian, Error, Tracked, ZeroCopyBuf, ZeroCopyBufMut, ZeroCopyReadBuf,
+};
+
+const PCAP_MAGIC: u32 = 0xa1b2c3d4;
+
+#[derive(FromBytes, IntoBytes, KnownLayout, Immutable, Unaligned, Debug)]
+#[repr(C)]
+struct PcapHeader {
+    magic: DynEndian<u32>,
+    major: DynEndian<u16>,
+    minor: DynEndian<u16>,
+    zone: DynEndian<i32>,
+    sigfigs: DynEndian<u32>,
+    snaplen: DynEndian<u32>,
+    network: DynEndian<u32>,
+}
+
+fn header(endian: Endian) -> PcapHeader {
+    PcapHeader {
+        magic: DynEndian::new(PCAP_MAGIC, endian),
+        major: DynEndian::new(2, endian),
+        minor: DynEndian::new(4, endian),
+        zone: DynEndian::new(-1, endian),
+        sigfigs: DynEndian::new(0, endian),
+        snaplen: DynEndian::new(65535, endian),
+        network: DynEndian::new(1, endian),
+    }
+}
+
+#[test]
+fn dyn_endian_header() {
+    for endian in [Endian::Big, Endian::Little] {
+        let mut data = BytesMut::new();
+        data.write(&header(endian));
+        let mut data = data.freeze();
+
+        let header = data.try_get::<PcapHeader>().unwrap();
+        assert_eq!(Endian::from_magic(header.magic, PCAP_MAGIC), Some(endian));
+        assert_eq!(header.major.get(endian), 2);
+        assert_eq!(header.minor.get(endian), 4);
+        assert_eq!(header.zone.get(endian), -1);
+        assert_eq!(header.snaplen.get(endian), 65535);
+        assert_eq!(header.network.get(endian), 1);
+        assert!(data.is_empty());
+    }
+}
+
+#[test]
+fn dyn_endian_bytes() {
+    let little = DynEndian::new(0x0102u16, Endian::Little);
+    assert_eq!(little.as_bytes(), b"\x02\x01");
+    assert_eq!(little.get(Endian::Big), 0x0201);
+
+    let mut big = DynEndian::new(1.5f32, Endian::Big);
+    assert_eq!(big.as_bytes(), 1.5f32.to_be_bytes());
+
+    big.set(-0.5, Endian::NATIVE);
+    assert_eq!(big.as_bytes(), (-0.5f32).to_ne_bytes());
+    assert_eq!(big.get(Endian::NATIVE), -0.5);
+}
+
+#[test]
+fn try_read_dyn() {
+    let mut data = Bytes::from_static(b"\xd4\xc3\xb2\xa1\x02\x00\x04\x00");
+    let endian = Endian::from_magic(data.try_read::<DynEndian<u32>>().unwrap(), PCAP_MAGIC);
+
+    let endian = endian.unwrap();
+    assert_eq!(endian, Endian::Little);
+    assert_eq!(data.try_read_dyn::<u16>(endian).unwrap(), 2);
+    assert_eq!(data.try_read_dyn::<u16>(endian).unwrap(), 4);
+
+    let mut out = BytesMut::new();
+    out.write_dyn(PCAP_MAGIC, Endian::Big);
+    out.write_dyn(2u16, Endian::Big);
+    assert_eq!(&out, &b"\xa1\xb2\xc3\xd4\x00\x02"[..]);
+}
+
+#[test]
+fn dyn_endian_error() {
+    let data: &[u8] = &b"\x00\x01\x02\x03"[..];
+    let magic = data.try_peek::<DynEndian<u32>>().unwrap();
+    assert_eq!(Endian::from_magic(*magic, PCAP_MAGIC), None);
+
+    let mut data = Tracked::new(data);
+    data.try_read_dyn::<u16>(Endian::Big).unwrap();
+    let err = data.try_read_dyn::<u32>(Endian::Big).unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Size {
+            offset: 2,
+            needed: 4,
+            available: 2,
+            ..
+        }
+    ));
+}
@@ -0,0 +1,99 @@
+use bytes::{Bytes, BytesMut};
+use zerocopy::{
+    network_endian, FromBytes, Immutable, IntoBytes, KnownLayout, TryFromBytes, Unaligned,
+};
+use zerocopy_buf::{Error, ZeroCopyBuf, ZeroCopyReadBuf};
+
+#[derive(FromBytes, KnownLayout, Immutable, Unaligned, Debug)]
+#[repr(C)]
+struct Packet {
+    len: network_endian::U16,
+    body: [u8],
+}
+
+#[test]
+fn size_error() {
+    let mut data = Bytes::from_static(b"\x00\x05abc");
+    let err = data.try_get_elems::<Packet>(5).unwrap_err();
+
+    assert_eq!(
+        err,
+        Error::Size {
+            type_name: core::any::type_name::<Packet>(),
+            offset: 0,
+            needed: 7,
+            available: 5,
+        }
+    );
+    assert_eq!(err.type_name(), core::any::type_name::<Packet>());
+    assert_eq!(data, b"\x00\x05abc"[..]);
+}
+
+#[test]
+fn size_error_mut() {
+    let mut data = BytesMut::from(&b"\x00"[..]);
+    let err = data.try_get::<network_endian::U16>().unwrap_err();
+
+    assert!(matches!(
+        err,
+        Error::Size {
+            needed: 2,
+            available: 1,
+            ..
+        }
+    ));
+    assert_eq!(data, b"\x00"[..]);
+}
+
+#[test]
+fn read_error() {
+    let mut data: &[u8] = b"\x00\x01\x02";
+    let err = data.try_read::<network_endian::U32>().unwrap_err();
+
+    assert!(matches!(
+        err,
+        Error::Size {
+            needed: 4,
+            available: 3,
+            ..
+        }
+    ));
+    assert_eq!(data, b"\x00\x01\x02");
+}
+
+#[test]
+fn display() {
+    let err = Bytes::new().try_get::<u8>().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "not enough bytes for `u8` at offset 0: needed 1, but only 0 available"
+    );
+
+    let err = (&[2u8][..]).try_get_checked::<bool>().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "the bytes at offset 0 are not a valid `bool`"
+    );
+}
+
+#[test]
+fn from_zerocopy() {
+    let err = network_endian::U32::ref_from_bytes(&[0; 3][..]).unwrap_err();
+    assert!(matches!(
+        Error::from(err),
+        Error::Size {
+            needed: 4,
+            available: 3,
+            ..
+        }
+    ));
+
+    let aligned = [0u32; 2];
+    let err = u32::ref_from_bytes(&aligned.as_bytes()[1..5]).unwrap_err();
+    assert!(matches!(Error::from(err), Error::Alignment { .. }));
+
+    let err: Box<dyn core::error::Error> = Box::new(Error::from(
+        bool::try_read_from_bytes(&[3][..]).unwrap_err(),
+    ));
+    assert!(err.to_string().contains("`bool`"));
+}
@@ -0,0 +1,98 @@
+use bytes::{BufMut, Bytes, BytesMut};
+use zerocopy::{network_endian, FromBytes, Immutable, KnownLayout, Unaligned};
+use zerocopy_buf::{Error, Frames};
+
+#[derive(FromBytes, KnownLayout, Immutable, Unaligned, Debug)]
+#[repr(C)]
+struct MessageHeader {
+    kind: u8,
+    len: network_endian::U16,
+}
+
+fn len(h: &MessageHeader) -> usize {
+    h.len.get() as usize
+}
+
+#[test]
+fn frames() {
+    let mut data = Bytes::from_static(b"\x01\x00\x02hi\x02\x00\x00\x03\x00\x05wor");
+    let frames: Vec<_> = Frames::new(&mut data, len)
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    assert_eq!(frames.len(), 2);
+    assert_eq!(frames[0].0.kind, 1);
+    assert_eq!(frames[0].1, b"hi"[..]);
+    assert_eq!(frames[1].0.kind, 2);
+    assert!(frames[1].1.is_empty());
+
+    assert_eq!(data, b"\x03\x00\x05wor"[..]);
+}
+
+#[test]
+fn frames_stream() {
+    let mut data = BytesMut::new();
+    data.put_slice(b"\x01\x00\x05he");
+
+    let mut frames = Frames::new(&mut data, len);
+    assert!(frames.next().is_none());
+    assert_eq!(frames.needed(), Some(3));
+
+    data.put_slice(b"llo\x02");
+    let mut frames = Frames::new(&mut data, len);
+    let (header, payload) = frames.next().unwrap().unwrap();
+    assert_eq!(header.kind, 1);
+    assert_eq!(payload, b"hello"[..]);
+
+    // only part of the next header is available
+    assert!(frames.next().is_none());
+    assert_eq!(frames.needed(), Some(2));
+    assert_eq!(frames.offset(), 8);
+    assert_eq!(data, b"\x02"[..]);
+}
+
+#[test]
+fn frames_length_includes_header() {
+    let mut data = Bytes::from_static(b"\x01\x00\x05hi\x01\x00\x03");
+    let mut frames = Frames::new(&mut data, len).length_includes_header(true);
+
+    assert_eq!(frames.next().unwrap().unwrap().1, b"hi"[..]);
+    assert!(frames.next().unwrap().unwrap().1.is_empty());
+    assert!(frames.next().is_none());
+    assert_eq!(frames.needed(), None);
+}
+
+#[test]
+fn frames_error() {
+    let mut data = Bytes::from_static(b"\x01\x00\x00\x01\x00\x02");
+    let mut frames = Frames::new(&mut data, len).length_includes_header(true);
+
+    let err = frames.next().unwrap().unwrap_err();
+    assert!(matches!(err, Error::Validity { offset: 0, .. }));
+    assert!(frames.next().is_none());
+    assert_eq!(data.len(), 6);
+
+    let mut data = Bytes::from_static(b"\x01\x00\x01a\x01\x01\x00");
+    let mut frames = Frames::new(&mut data, len).max_frame_size(16);
+
+    frames.next().unwrap().unwrap();
+    let err = frames.next().unwrap().unwrap_err();
+    assert_eq!(
+        err,
+        Error::TooLarge {
+            type_name: core::any::type_name::<MessageHeader>(),
+            offset: 4,
+            size: 259,
+            max: 16,
+        }
+    );
+    assert!(frames.next().is_none());
+    assert_eq!(data, b"\x01\x01\x00"[..]);
+
+    let mut data = Bytes::from_static(b"\x01\xff\xff");
+    let mut frames = Frames::new(&mut data, |_: &MessageHeader| usize::MAX).max_frame_size(16);
+
+    let err = frames.next().unwrap().unwrap_err();
+    assert!(matches!(err, Error::Validity { offset: 0, .. }));
+    assert_eq!(frames.needed(), None);
+}
@@ -0,0 +1,69 @@
+use bytes::{Bytes, BytesMut};
+use zerocopy::network_endian::{U16, U32};
+use zerocopy_buf::{Error, ZeroCopySlice};
+
+// A tiny offset table: a count, followed by that many u16 offsets into the message.
+const MESSAGE: &[u8] = b"\x00\x02\x00\x06\x00\x0a\xde\xad\xbe\xef\xca\xfe\xba\xbe";
+
+#[test]
+fn get_at() {
+    let data = Bytes::from_static(MESSAGE);
+    let count = data.get_at::<U16>(0).unwrap();
+    let offsets = data.get_elems_at::<[U16]>(2, count.get() as usize).unwrap();
+
+    let values: Vec<_> = offsets
+        .iter()
+        .map(|o| data.get_at::<U32>(o.get() as usize).unwrap())
+        .collect();
+    drop(data);
+
+    assert_eq!(values[0].get(), 0xdeadbeef);
+    assert_eq!(values[1].get(), 0xcafebabe);
+}
+
+#[test]
+fn get_at_slice() {
+    let data = MESSAGE;
+    let value = data.get_at::<U32>(10).unwrap();
+    assert_eq!(value.get(), 0xcafebabe);
+    assert_eq!(data, MESSAGE);
+}
+
+#[test]
+fn get_mut_at() {
+    let mut data = BytesMut::from(MESSAGE);
+    data.get_mut_at::<U32>(6).unwrap().set(0x01020304);
+    data.get_elems_mut_at::<[U16]>(2, 2).unwrap()[1].set(0x0006);
+
+    assert_eq!(data.get_at::<U32>(6).unwrap().get(), 0x01020304);
+    assert_eq!(&data[..6], b"\x00\x02\x00\x06\x00\x06");
+}
+
+#[test]
+fn get_at_error() {
+    let data = Bytes::from_static(MESSAGE);
+
+    let err = data.get_at::<U32>(12).unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Size {
+            offset: 12,
+            needed: 4,
+            available: 2,
+            ..
+        }
+    ));
+
+    let err = data.get_elems_at::<[U16]>(20, 2).unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Size {
+            offset: 20,
+            needed: 4,
+            available: 0,
+            ..
+        }
+    ));
+
+    assert_eq!(data, MESSAGE);
+}
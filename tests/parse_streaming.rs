@@ -0,0 +1,131 @@
+use bytes::{Buf, BufMut, BytesMut};
+use zerocopy::{network_endian, FromBytes, Immutable, KnownLayout, TryFromBytes, Unaligned};
+use zerocopy_buf::{Error, Parsed, ZeroCopyBuf};
+
+#[derive(FromBytes, KnownLayout, Immutable, Unaligned)]
+#[repr(C)]
+struct Header {
+    kind: u8,
+    len: network_endian::U16,
+}
+
+#[derive(TryFromBytes, KnownLayout, Immutable, Unaligned, Clone, Copy, PartialEq, Debug)]
+#[repr(u8)]
+enum Kind {
+    Ping = 1,
+    Data = 2,
+}
+
+fn parse(b: &mut &[u8]) -> Result<(u8, Vec<u8>), Error> {
+    let header = b.try_get::<Header>()?;
+    let body = b.try_get_elems::<[u8]>(header.len.get() as usize)?;
+    Ok((header.kind, body.to_vec()))
+}
+
+fn parse_checked(b: &mut &[u8]) -> Result<(Kind, Vec<u8>), Error> {
+    let kind = *b.try_get_checked::<Kind>()?;
+    let len = b.try_get::<network_endian::U16>()?;
+    Ok((kind, b.try_get_elems::<[u8]>(len.get() as usize)?.to_vec()))
+}
+
+/// Parse from a view of the receive buffer, which is advanced once the message is complete.
+fn stream<R>(buf: &mut BytesMut, f: impl FnOnce(&mut &[u8]) -> Result<R, Error>) -> Parsed<R> {
+    let mut view = &buf[..];
+    let res = view.parse_streaming(f);
+    let consumed = buf.len() - view.len();
+    buf.advance(consumed);
+    res
+}
+
+#[test]
+fn parse_streaming() {
+    let mut data = BytesMut::new();
+    assert_eq!(stream(&mut data, parse), Parsed::Incomplete { needed: 3 });
+
+    data.put_slice(b"\x02\x00");
+    assert_eq!(stream(&mut data, parse), Parsed::Incomplete { needed: 1 });
+
+    data.put_slice(b"\x04ab");
+    assert_eq!(stream(&mut data, parse), Parsed::Incomplete { needed: 2 });
+    assert_eq!(data.len(), 5);
+
+    data.put_slice(b"cd\x01");
+    assert_eq!(
+        stream(&mut data, parse),
+        Parsed::Complete((2, b"abcd".to_vec()))
+    );
+    assert_eq!(data, b"\x01"[..]);
+}
+
+#[test]
+fn parse_streaming_invalid() {
+    let mut data = BytesMut::from(&b"\x03\x00\x01"[..]);
+    let res = stream(&mut data, parse_checked);
+
+    assert!(matches!(res, Parsed::Invalid(Error::Validity { .. })));
+    assert_eq!(data.len(), 3);
+
+    let mut data = BytesMut::from(&b"\x01\x00\x01"[..]);
+    assert_eq!(
+        stream(&mut data, parse_checked),
+        Parsed::Incomplete { needed: 1 }
+    );
+
+    data.put_slice(b"!");
+    assert_eq!(
+        stream(&mut data, parse_checked),
+        Parsed::Complete((Kind::Ping, b"!".to_vec()))
+    );
+
+    data.put_slice(b"\x02\x00\x02hi");
+    assert_eq!(
+        stream(&mut data, parse_checked),
+        Parsed::Complete((Kind::Data, b"hi".to_vec()))
+    );
+    assert!(data.is_empty());
+}
+
+#[test]
+fn parsed_from_result() {
+    let overflow: Result<(), _> = Err(Error::Size {
+        type_name: "[u8]",
+        offset: 0,
+        needed: usize::MAX,
+        available: 4,
+    });
+    assert!(matches!(Parsed::from(overflow), Parsed::Invalid(_)));
+
+    let ok: Parsed<u8> = Ok(1).into();
+    assert!(ok.is_complete());
+    assert_eq!(ok.map(|x| x + 1), Parsed::Complete(2));
+}
+
+#[test]
+fn parse_streaming_section() {
+    fn parse_section(b: &mut &[u8]) -> Result<Vec<u8>, Error> {
+        let len = b.try_get::<network_endian::U16>()?;
+        b.with_section(len.get() as usize, |s| {
+            let inner = s.try_get::<network_endian::U16>()?;
+            Ok(s.try_get_elems::<[u8]>(inner.get() as usize)?.to_vec())
+        })
+    }
+
+    let mut data = BytesMut::from(&b"\x00\x04\x00\x05"[..]);
+    assert_eq!(
+        stream(&mut data, parse_section),
+        Parsed::Incomplete { needed: 2 }
+    );
+
+    // the inner length runs past the end of the section, which is already in the buffer.
+    data.put_slice(b"ab\xff\xff\xff");
+    assert_eq!(
+        stream(&mut data, parse_section),
+        Parsed::Invalid(Error::Overrun {
+            type_name: "[u8]",
+            offset: 0,
+            needed: 5,
+            available: 2,
+        })
+    );
+    assert_eq!(data.len(), 9);
+}
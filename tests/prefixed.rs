@@ -0,0 +1,141 @@
+use bytes::{Bytes, BytesMut};
+use zerocopy::network_endian::{U16, U32};
+use zerocopy::{BigEndian, LittleEndian};
+use zerocopy_buf::{Error, Length, Tracked, ZeroCopyBuf, ZeroCopyBufMut, U24};
+
+#[test]
+fn try_get_prefixed() {
+    let mut data = BytesMut::new();
+    data.write_prefixed::<u8>(b"ssh-ed25519").unwrap();
+    data.write_prefixed::<U32>(b"").unwrap();
+    data.write_prefixed::<U24<BigEndian>>(b"cert").unwrap();
+    let mut data = data.freeze();
+
+    let a: Bytes = data.try_get_prefixed::<u8>().unwrap();
+    assert_eq!(a, b"ssh-ed25519"[..]);
+    assert!(data.try_get_prefixed::<U32>().unwrap().is_empty());
+    assert_eq!(
+        data.try_get_prefixed::<U24<BigEndian>>().unwrap(),
+        b"cert"[..]
+    );
+    assert!(data.is_empty());
+
+    let mut data: &[u8] = &b"\x02\x00hi"[..];
+    let b: &[u8] = data
+        .try_get_prefixed::<zerocopy::U16<LittleEndian>>()
+        .unwrap();
+    assert_eq!(b, b"hi");
+}
+
+#[test]
+fn try_get_prefixed_error() {
+    let mut data: &[u8] = &b"\x00\x05hell"[..];
+    let err = data.try_get_prefixed::<U16>().unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Size {
+            needed: 7,
+            available: 6,
+            ..
+        }
+    ));
+    assert_eq!(data.len(), 6);
+
+    let mut data: &[u8] = &b"\x00"[..];
+    let err = data.try_get_prefixed::<U16>().unwrap_err();
+    assert!(matches!(err, Error::Size { needed: 2, .. }));
+}
+
+#[test]
+fn try_get_prefixed_elems() {
+    let mut data = Bytes::from_static(b"\x00\x06\x13\x01\x13\x02\x13\x03\xff");
+    let suites = data.try_get_prefixed_elems::<U16, U16>().unwrap();
+
+    let suites: Vec<u16> = suites.iter().map(|s| s.get()).collect();
+    assert_eq!(suites, [0x1301, 0x1302, 0x1303]);
+    assert_eq!(data, b"\xff"[..]);
+
+    let mut data: &[u8] = &b"\x03\x00\x01\x00"[..];
+    let err = data.try_get_prefixed_elems::<u8, U16>().unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Leftover {
+            offset: 3,
+            remaining: 1,
+            ..
+        }
+    ));
+    assert_eq!(data.len(), 4);
+}
+
+#[test]
+fn write_prefixed_too_large() {
+    let mut data = BytesMut::new();
+    let err = data.write_prefixed::<U16>(&[0; 65536]).unwrap_err();
+    assert!(matches!(
+        err,
+        Error::TooLarge {
+            size: 65536,
+            max: 65535,
+            ..
+        }
+    ));
+    assert!(data.is_empty());
+
+    assert_eq!(<U24<BigEndian>>::MAX, 0xffffff);
+    assert!(<U24<BigEndian>>::from_usize(0x1000000).is_none());
+    assert_eq!(<U32 as Length>::MAX, u32::MAX as usize);
+}
+
+#[test]
+fn tracked_prefixed() {
+    let mut data = Tracked::with_trace(&b"\x01a\x02b"[..]);
+    assert_eq!(data.try_get_prefixed::<u8>().unwrap().into_inner(), b"a");
+
+    let err = data.try_get_prefixed::<u8>().unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Size {
+            offset: 2,
+            needed: 3,
+            ..
+        }
+    ));
+    assert_eq!(data.offset(), 2);
+    assert_eq!(data.trace().len(), 2);
+}
+
+#[test]
+fn tracked_prefixed_elems() {
+    let mut data = Tracked::with_trace(&b"\x00\x00\x00\x00\x02\x13\x01\x03\x00\x01\x00"[..]);
+    data.try_get::<U32>().unwrap();
+
+    let suites = data.try_get_prefixed_elems::<u8, U16>().unwrap();
+    assert_eq!(suites[0].get(), 0x1301);
+    assert_eq!(data.offset(), 7);
+    assert_eq!((data.trace()[2].offset, data.trace()[2].size), (5, 2));
+
+    let err = data.try_get_prefixed_elems::<u8, U16>().unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Leftover {
+            offset: 10,
+            remaining: 1,
+            ..
+        }
+    ));
+    assert_eq!(data.offset(), 7);
+
+    let mut data = Tracked::new(&b"\x00\x00\x00\x00\x05hell"[..]);
+    data.try_get::<U32>().unwrap();
+    let err = data.try_get_prefixed::<u8>().unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Size {
+            offset: 4,
+            needed: 6,
+            available: 5,
+            ..
+        }
+    ));
+}
@@ -0,0 +1,115 @@
+use bytes::{Buf, Bytes, BytesMut};
+use zerocopy_buf::{Error, QuicVarInt, Tracked, ZeroCopyBuf, ZeroCopyBufMut, ZeroCopyReadBuf};
+
+// examples from RFC 9000 appendix A.1
+const EXAMPLES: [(&[u8], u64); 4] = [
+    (b"\xc2\x19\x7c\x5e\xff\x14\xe8\x8c", 151288809941952652),
+    (b"\x9d\x7f\x3e\x7d", 494878333),
+    (b"\x7b\xbd", 15293),
+    (b"\x25", 37),
+];
+
+#[test]
+fn quic_varint_read() {
+    for (bytes, value) in EXAMPLES {
+        let mut data = bytes;
+        let n = data.try_read_quic_varint().unwrap();
+        assert_eq!(n.get(), value);
+        assert_eq!(n.encoded_len(), bytes.len());
+        assert!(data.is_empty());
+
+        let mut data = Bytes::from_static(bytes);
+        assert_eq!(data.try_get_quic_varint().unwrap().get(), value);
+        assert!(data.is_empty());
+    }
+
+    // non-minimal encodings are allowed
+    let mut data: &[u8] = &b"\x40\x25"[..];
+    assert_eq!(data.try_read_quic_varint().unwrap().get(), 37);
+}
+
+#[test]
+fn quic_varint_write() {
+    for (bytes, value) in EXAMPLES {
+        let mut data = BytesMut::new();
+        data.write_quic_varint(QuicVarInt::new(value).unwrap());
+        assert_eq!(&data, bytes);
+    }
+
+    let mut data = BytesMut::new();
+    data.write_quic_varint_with_len(QuicVarInt::from(37u8), 4);
+    data.write_quic_varint_with_len(QuicVarInt::MAX, 8);
+    assert_eq!(&data[..4], b"\x80\x00\x00\x25");
+    assert_eq!(data.try_read_quic_varint().unwrap().get(), 37);
+    assert_eq!(data.try_read_quic_varint().unwrap(), QuicVarInt::MAX);
+}
+
+#[test]
+#[should_panic]
+fn quic_varint_write_too_short() {
+    BytesMut::new().write_quic_varint_with_len(QuicVarInt::from(15293u16), 1);
+}
+
+#[test]
+fn quic_varint_range() {
+    assert_eq!(QuicVarInt::MAX.get(), (1 << 62) - 1);
+    assert!(QuicVarInt::new(1 << 62).is_none());
+    assert_eq!(QuicVarInt::from_u32(u32::MAX).encoded_len(), 8);
+    assert_eq!(QuicVarInt::from(63u8).encoded_len(), 1);
+    assert_eq!(QuicVarInt::from(64u8).encoded_len(), 2);
+    assert_eq!(QuicVarInt::decoded_len(0x9d), 4);
+}
+
+#[test]
+fn quic_varint_error() {
+    let mut data: &[u8] = &b"\x9d\x7f\x3e"[..];
+    let err = data.try_read_quic_varint().unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Size {
+            needed: 4,
+            available: 3,
+            ..
+        }
+    ));
+    assert_eq!(data.len(), 3);
+
+    let err = data.try_get_quic_varint().unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Size {
+            needed: 4,
+            available: 3,
+            ..
+        }
+    ));
+    assert_eq!(err.type_name(), core::any::type_name::<QuicVarInt>());
+
+    let mut data: &[u8] = &[][..];
+    let err = data.try_read_quic_varint().unwrap_err();
+    assert!(matches!(err, Error::Size { needed: 1, .. }));
+
+    let err = data.try_get_quic_varint().unwrap_err();
+    assert_eq!(err, data.try_read_quic_varint().unwrap_err());
+}
+
+#[test]
+fn quic_varint_chunks() {
+    let mut data = Bytes::from_static(b"\x9d\x7f").chain(Bytes::from_static(b"\x3e\x7d"));
+    assert_eq!(data.try_read_quic_varint().unwrap().get(), 494878333);
+}
+
+#[test]
+fn tracked_quic_varint() {
+    let mut data = Tracked::with_trace(&b"\x25\x40\x02hi\x7b"[..]);
+    data.try_read_quic_varint().unwrap();
+
+    let len = data.try_get_quic_varint().unwrap();
+    let body = data.try_get_elems::<[u8]>(len.to_usize()).unwrap();
+    assert_eq!(&*body, b"hi");
+
+    let err = data.try_get_quic_varint().unwrap_err();
+    assert_eq!(err.offset(), 5);
+    assert_eq!(data.trace().len(), 3);
+    assert_eq!(data.trace()[1].size, 2);
+}
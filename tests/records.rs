@@ -0,0 +1,58 @@
+use bytes::{Bytes, BytesMut};
+use zerocopy::{network_endian, FromBytes, Immutable, KnownLayout, Unaligned};
+use zerocopy_buf::ZeroCopyBuf;
+
+#[derive(FromBytes, KnownLayout, Immutable, Unaligned)]
+#[repr(C)]
+struct FlowRecord {
+    src_port: network_endian::U16,
+    dst_port: network_endian::U16,
+    packets: network_endian::U32,
+}
+
+const FLOWS: &[u8] = b"\x00\x50\x04\xd2\x00\x00\x00\x0a\x01\xbb\x04\xd3\x00\x00\x00\x14\xca\xfe";
+
+#[test]
+fn records() {
+    let data = Bytes::from_static(FLOWS);
+    let mut records = data.records::<FlowRecord>();
+    assert_eq!(records.len(), 2);
+
+    let a = records.next().unwrap();
+    let b = records.next().unwrap();
+    assert!(records.next().is_none());
+    assert_eq!(records.remainder(), b"\xca\xfe");
+
+    let remainder = records.into_remainder();
+    assert_eq!(*remainder, b"\xca\xfe"[..]);
+
+    assert_eq!(a.src_port.get(), 80);
+    assert_eq!(a.packets.get(), 10);
+    assert_eq!(b.src_port.get(), 443);
+    assert_eq!(b.packets.get(), 20);
+}
+
+#[test]
+fn records_rev() {
+    let data = BytesMut::from(FLOWS);
+    let ports: Vec<_> = data
+        .records::<FlowRecord>()
+        .rev()
+        .map(|r| r.dst_port.get())
+        .collect();
+    assert_eq!(ports, [1235, 1234]);
+}
+
+#[test]
+fn records_exact() {
+    let data: &[u8] = &FLOWS[..16];
+    let mut records = data.records::<FlowRecord>();
+    assert_eq!(records.len(), 2);
+    assert!(records.remainder().is_empty());
+
+    records.next_back().unwrap();
+    assert_eq!(records.len(), 1);
+    records.next().unwrap();
+    assert_eq!(records.len(), 0);
+    assert!(records.next_back().is_none());
+}
@@ -0,0 +1,92 @@
+use bytes::{Bytes, BytesMut};
+use zerocopy::network_endian::{U16, U32};
+use zerocopy_buf::{Error, Tracked, ZeroCopyBuf};
+
+#[test]
+fn try_split_section() {
+    let mut data = Bytes::from_static(b"\x00\x01\x00\x02rest");
+    let mut section = data.try_split_section(4).unwrap();
+
+    assert_eq!(data, b"rest"[..]);
+    assert_eq!(section.try_get::<U16>().unwrap().get(), 1);
+    assert_eq!(section.try_get::<U16>().unwrap().get(), 2);
+
+    let err = section.try_get::<U16>().unwrap_err();
+    assert!(matches!(err, Error::Size { available: 0, .. }));
+
+    let mut data = BytesMut::from(&b"\x01\x02\x03"[..]);
+    let section: BytesMut = data.try_split_section(1).unwrap();
+    assert_eq!(section, b"\x01"[..]);
+    assert_eq!(data, b"\x02\x03"[..]);
+}
+
+#[test]
+fn try_split_section_error() {
+    let mut data: &[u8] = &b"\x01\x02\x03"[..];
+    let err = data.try_split_section(4).unwrap_err();
+
+    assert!(matches!(
+        err,
+        Error::Size {
+            needed: 4,
+            available: 3,
+            ..
+        }
+    ));
+    assert_eq!(data.len(), 3);
+}
+
+#[test]
+fn with_section() {
+    let mut data = Bytes::from_static(b"\x00\x00\x00\x07\xff");
+    let value = data
+        .with_section(4, |s| Ok(s.try_get::<U32>()?.get()))
+        .unwrap();
+
+    assert_eq!(value, 7);
+    assert_eq!(data, b"\xff"[..]);
+
+    let mut data: &[u8] = &b"\x00\x01\x02\x03\xff"[..];
+    let err = data
+        .with_section(4, |s| Ok(s.try_get::<U16>()?.get()))
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        Error::Leftover {
+            type_name: "[u8]",
+            offset: 2,
+            remaining: 2,
+        }
+    ));
+    assert_eq!(data, b"\xff");
+}
+
+#[test]
+fn with_section_tracked() {
+    let mut data = Tracked::with_trace(&b"\x01\x00\x01\x00\x02\xff"[..]);
+    data.try_get::<u8>().unwrap();
+
+    let err = data
+        .with_section(4, |s| Ok(s.try_get::<U16>()?.get()))
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Leftover {
+            type_name: "[u8]",
+            offset: 3,
+            remaining: 2,
+        }
+    ));
+    assert_eq!(data.offset(), 5);
+
+    let trace = data.trace();
+    assert_eq!(trace.len(), 3);
+    assert_eq!((trace[1].offset, trace[1].size), (1, 4));
+    assert_eq!((trace[2].offset, trace[2].size), (1, 2));
+
+    let err = data
+        .with_section(1, |s| Ok(s.try_get::<U16>()?.get()))
+        .unwrap_err();
+    assert_eq!(err.offset(), 5);
+}
@@ -0,0 +1,109 @@
+use bytes::Bytes;
+use zerocopy::network_endian::U16;
+use zerocopy_buf::{Error, Tlv};
+
+#[test]
+fn tlv() {
+    let data: &[u8] = b"\x01\x04\xff\xff\xff\x00\x03\x00\x06\x01\xaa";
+    let options: Vec<_> = Tlv::<_, u8, u8>::new(data)
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    assert_eq!(
+        options,
+        [
+            (1, &b"\xff\xff\xff\x00"[..]),
+            (3, &b""[..]),
+            (6, &b"\xaa"[..])
+        ]
+    );
+}
+
+#[test]
+fn tlv_bytes() {
+    // RADIUS attributes: the length includes the tag and length fields
+    let data = Bytes::from_static(b"\x01\x07alice\x04\x06\x7f\x00\x00\x01");
+    let mut attrs = Tlv::<_, u8, u8>::new(data.clone()).length_includes_header(true);
+
+    let (tag, user) = attrs.next().unwrap().unwrap();
+    assert_eq!(tag, 1);
+    assert_eq!(user, b"alice"[..]);
+    assert_eq!(user.as_ptr(), data[2..].as_ptr());
+
+    let (tag, addr) = attrs.next().unwrap().unwrap();
+    assert_eq!(tag, 4);
+    assert_eq!(addr, b"\x7f\x00\x00\x01"[..]);
+
+    assert!(attrs.next().is_none());
+    assert_eq!(attrs.offset(), 13);
+}
+
+#[test]
+fn tlv_align() {
+    // netlink-style attributes: u16 length including the header, u16 type, padded to 4 bytes
+    let data: &[u8] = b"\x00\x01\x00\x05a\x00\x00\x00\x00\x02\x00\x06bc";
+    let mut attrs = Tlv::<_, U16, U16>::new(data)
+        .length_includes_header(true)
+        .align(4);
+
+    let (tag, value) = attrs.next().unwrap().unwrap();
+    assert_eq!(tag.get(), 1);
+    assert_eq!(value, b"a");
+
+    // the last attribute is not padded
+    let (tag, value) = attrs.next().unwrap().unwrap();
+    assert_eq!(tag.get(), 2);
+    assert_eq!(value, b"bc");
+
+    assert!(attrs.next().is_none());
+    assert!(attrs.remainder().is_empty());
+}
+
+#[test]
+fn tlv_end_tag() {
+    let data: &[u8] = b"\x35\x01\x05\xff\x00\x00";
+    let mut options = Tlv::<_, u8, u8>::new(data).end_tag(0xff);
+
+    assert_eq!(options.next().unwrap().unwrap(), (0x35, &b"\x05"[..]));
+    assert!(options.next().is_none());
+    assert!(options.next().is_none());
+    assert_eq!(options.remainder(), b"\x00\x00");
+}
+
+#[test]
+fn tlv_error() {
+    let data: &[u8] = b"\x01\x01a\x02\x05abc";
+    let mut options = Tlv::<_, u8, u8>::new(data);
+
+    options.next().unwrap().unwrap();
+    let err = options.next().unwrap().unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Size {
+            offset: 5,
+            needed: 5,
+            available: 3,
+            ..
+        }
+    ));
+    assert!(options.next().is_none());
+    assert_eq!(options.remainder(), b"\x02\x05abc");
+
+    let data: &[u8] = b"\x01\x01";
+    let mut attrs = Tlv::<_, u8, u8>::new(data).length_includes_header(true);
+    let err = attrs.next().unwrap().unwrap_err();
+    assert!(matches!(err, Error::Validity { offset: 1, .. }));
+
+    let data: &[u8] = b"\x00\x01\x00";
+    let mut attrs = Tlv::<_, U16, U16>::new(data);
+    let err = attrs.next().unwrap().unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Size {
+            offset: 2,
+            needed: 2,
+            available: 1,
+            ..
+        }
+    ));
+}
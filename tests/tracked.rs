@@ -0,0 +1,128 @@
+use bytes::{Buf, Bytes};
+use zerocopy::{network_endian, FromBytes, Immutable, KnownLayout, Unaligned};
+use zerocopy_buf::{Error, TraceEntry, Tracked, ZeroCopyBuf, ZeroCopyReadBuf};
+
+#[derive(FromBytes, KnownLayout, Immutable, Unaligned)]
+#[repr(C)]
+struct UdpHeader {
+    src_port: network_endian::U16,
+    dst_port: network_endian::U16,
+    length: network_endian::U16,
+    checksum: network_endian::U16,
+}
+
+fn read_generic(buf: &mut impl ZeroCopyReadBuf) -> Result<network_endian::U32, Error> {
+    buf.try_read()
+}
+
+#[test]
+fn offsets() {
+    let data = b"\x00\x35\x00\x35\x00\x0c\x00\x00\x01\x02\x03\x04\x05";
+    let mut data = Tracked::new(Bytes::from_static(data));
+
+    let header = data.try_get::<UdpHeader>().unwrap();
+    assert_eq!(header.length.get(), 12);
+    assert_eq!(data.offset(), 8);
+
+    data.advance(1);
+    assert_eq!(data.offset(), 9);
+
+    assert_eq!(read_generic(&mut data).unwrap().get(), 0x02030405);
+    assert_eq!(data.offset(), 13);
+    assert!(data.trace().is_empty());
+
+    let err = data.try_get::<u8>().unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Size {
+            offset: 13,
+            needed: 1,
+            available: 0,
+            ..
+        }
+    ));
+
+    // generic code reports the absolute offset too.
+    assert_eq!(read_generic(&mut data).unwrap_err().offset(), 13);
+}
+
+#[test]
+fn errors() {
+    let mut data = Tracked::new(&b"\x00\x03\x02"[..]);
+    data.try_read::<u8>().unwrap();
+
+    let err = data.try_read::<network_endian::U32>().unwrap_err();
+    assert_eq!(err.offset(), 1);
+
+    let err = data.try_peek_elems::<[u8]>(5).unwrap_err();
+    assert_eq!(err.offset(), 1);
+
+    let err = data.try_get_checked::<bool>().unwrap_err();
+    assert!(matches!(err, Error::Validity { offset: 1, .. }));
+
+    assert_eq!(data.offset(), 1);
+    assert_eq!(data.into_inner(), b"\x03\x02");
+}
+
+#[test]
+fn trace() {
+    let data = b"\x00\x35\x00\x35\x00\x0c\x00\x00\x01\x02\x03\x04\x05";
+    let mut data = Tracked::with_trace(&data[..]);
+
+    data.try_get::<UdpHeader>().unwrap();
+    data.try_peek::<u8>().unwrap();
+    data.try_read::<u8>().unwrap();
+    data.advance(1);
+    data.try_get_elems::<[network_endian::U16]>(1).unwrap();
+
+    assert_eq!(
+        data.trace(),
+        [
+            TraceEntry {
+                offset: 0,
+                type_name: core::any::type_name::<UdpHeader>(),
+                size: 8,
+            },
+            TraceEntry {
+                offset: 8,
+                type_name: "u8",
+                size: 1,
+            },
+            TraceEntry {
+                offset: 9,
+                type_name: "[u8]",
+                size: 1,
+            },
+            TraceEntry {
+                offset: 10,
+                type_name: core::any::type_name::<[network_endian::U16]>(),
+                size: 2,
+            },
+        ]
+    );
+}
+
+#[test]
+fn trace_generic() {
+    let data = (&b"\x00\x01\x02"[..]).chain(&b"\x03\x04"[..]);
+    let mut data = Tracked::with_trace(data);
+
+    data.advance(1);
+    assert_eq!(read_generic(&mut data).unwrap().get(), 0x01020304);
+
+    assert_eq!(
+        data.trace(),
+        [
+            TraceEntry {
+                offset: 0,
+                type_name: "[u8]",
+                size: 1,
+            },
+            TraceEntry {
+                offset: 1,
+                type_name: core::any::type_name::<network_endian::U32>(),
+                size: 4,
+            },
+        ]
+    );
+}
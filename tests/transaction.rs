@@ -0,0 +1,95 @@
+use bytes::Bytes;
+use zerocopy::{network_endian, FromBytes, Immutable, KnownLayout, Unaligned};
+use zerocopy_buf::{Error, Tracked, ZeroCopyBuf};
+
+#[derive(FromBytes, KnownLayout, Immutable, Unaligned, Debug)]
+#[repr(C)]
+struct Header {
+    kind: u8,
+    options: u8,
+    len: network_endian::U16,
+}
+
+#[derive(Debug, PartialEq)]
+struct Message {
+    kind: u8,
+    options: Vec<u8>,
+    body: Vec<u8>,
+}
+
+fn parse<B: ZeroCopyBuf>(b: &mut B) -> Result<Message, Error> {
+    let header = b.try_get::<Header>()?;
+    let options = b.try_get_elems::<[u8]>(header.options as usize)?;
+    let body = b.try_get_elems::<[u8]>(header.len.get() as usize)?;
+    Ok(Message {
+        kind: header.kind,
+        options: options.to_vec(),
+        body: body.to_vec(),
+    })
+}
+
+#[test]
+fn transaction_commit() {
+    let mut data = Bytes::from_static(b"\x01\x01\x00\x02\xaahi\xff");
+    let msg = data.transaction(parse).unwrap();
+
+    assert_eq!(
+        msg,
+        Message {
+            kind: 1,
+            options: vec![0xaa],
+            body: b"hi".to_vec(),
+        }
+    );
+    assert_eq!(data, b"\xff"[..]);
+}
+
+#[test]
+fn transaction_rollback() {
+    let mut data = Bytes::from_static(b"\x01\x01\x00\x02\xaah");
+    let err = data.transaction(parse).unwrap_err();
+
+    assert!(matches!(err, Error::Size { .. }));
+    assert_eq!(data, b"\x01\x01\x00\x02\xaah"[..]);
+
+    let mut data: &[u8] = &b"\x01\x01\x00\x02\xaah"[..];
+    data.transaction(parse).unwrap_err();
+    assert_eq!(data.len(), 6);
+}
+
+#[test]
+fn transaction_suffix() {
+    let mut data = Bytes::from_static(b"\x00\x01\x00\x02\xaa\xbb");
+    let trailer = data
+        .transaction(|b| b.try_get_suffix::<network_endian::U16>().map(|t| t.get()))
+        .unwrap();
+
+    assert_eq!(trailer, 0xaabb);
+    assert_eq!(data, b"\x00\x01\x00\x02"[..]);
+
+    let mut data = Tracked::new(Bytes::from_static(b"\x00\x01\x00\x02\xaa\xbb"));
+    data.transaction(|b| {
+        b.try_get::<network_endian::U16>()?;
+        b.try_get_suffix::<network_endian::U16>()
+    })
+    .unwrap();
+
+    assert_eq!(data.offset(), 2);
+    assert_eq!(*data.get_ref(), b"\x00\x02"[..]);
+}
+
+#[test]
+fn transaction_tracked() {
+    let mut data = Tracked::with_trace(&b"\x00\x01\x00\x02\xaa\x01"[..]);
+    data.transaction(parse).unwrap_err();
+
+    assert_eq!(data.offset(), 0);
+    assert!(data.trace().is_empty());
+
+    let mut data = Tracked::with_trace(Bytes::from_static(b"\x00\x01\x00\x01\xaah\xff"));
+    let msg = data.transaction(parse).unwrap();
+    assert_eq!(msg.body, b"h");
+    assert_eq!(data.offset(), 6);
+    assert_eq!(data.trace().len(), 3);
+    assert_eq!(*data.get_ref(), b"\xff"[..]);
+}
@@ -1,6 +1,6 @@
 use bytes::{Bytes, BytesMut};
 use zerocopy::{network_endian, FromBytes, Immutable, IntoBytes, KnownLayout, Unaligned};
-use zerocopy_buf::ZeroCopyBuf;
+use zerocopy_buf::{Error, ZeroCopyBuf};
 
 #[derive(FromBytes, KnownLayout, Immutable, Unaligned, IntoBytes, PartialEq, Debug)]
 #[repr(C)]
@@ -109,5 +109,13 @@ fn try_get_error() {
     let err = data.try_get::<Ipv4Header>().unwrap_err();
 
     assert_eq!(data.len(), 19);
-    assert!(err.into_src().is_empty());
+    assert!(matches!(
+        err,
+        Error::Size {
+            offset: 0,
+            needed: 20,
+            available: 19,
+            ..
+        }
+    ));
 }
@@ -0,0 +1,95 @@
+use bytes::{Bytes, BytesMut};
+use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout};
+use zerocopy_buf::{Error, ZeroCopyBuf};
+
+#[derive(FromBytes, KnownLayout, Immutable, IntoBytes, PartialEq, Debug)]
+#[repr(C)]
+struct RingHeader {
+    head: u64,
+    tail: u64,
+}
+
+#[derive(FromBytes, KnownLayout, Immutable)]
+#[repr(C)]
+struct Ring {
+    header: RingHeader,
+    slots: [u32],
+}
+
+#[repr(C, align(8))]
+struct Aligned([u8; 32]);
+
+static DATA: Aligned = Aligned([
+    1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 4, 0, 0, 0, 5, 0, 0, 0, 6, 0, 0, 0,
+]);
+
+fn ne_u64(b: &[u8]) -> u64 {
+    u64::from_ne_bytes(b.try_into().unwrap())
+}
+
+#[test]
+fn try_get_aligned() {
+    let mut data = Bytes::from_static(&DATA.0);
+    let header = data.try_get_aligned::<RingHeader>().unwrap();
+
+    assert_eq!(data, DATA.0[16..]);
+    drop(data);
+
+    assert_eq!(
+        *header,
+        RingHeader {
+            head: ne_u64(&DATA.0[..8]),
+            tail: ne_u64(&DATA.0[8..16]),
+        }
+    );
+}
+
+#[test]
+fn try_get_aligned_error() {
+    let mut data = Bytes::from_static(&DATA.0[4..]);
+    let err = data.try_get_aligned::<RingHeader>().unwrap_err();
+
+    assert!(matches!(err, Error::Alignment { .. }));
+    assert_eq!(data, DATA.0[4..]);
+
+    let mut data = Bytes::from_static(&DATA.0[..8]);
+    let err = data.try_get_aligned::<RingHeader>().unwrap_err();
+
+    assert!(matches!(err, Error::Size { .. }));
+    assert_eq!(data, DATA.0[..8]);
+}
+
+#[test]
+fn try_get_elems_aligned() {
+    let mut data: &[u8] = &DATA.0[..];
+    let ring = data.try_get_elems_aligned::<Ring>(4).unwrap();
+
+    assert_eq!(ring.header.head, ne_u64(&DATA.0[..8]));
+    assert_eq!(ring.slots.len(), 4);
+    assert!(data.is_empty());
+}
+
+#[test]
+fn try_get_elems_aligned_write() {
+    let mut data = BytesMut::from(&DATA.0[..]);
+    // BytesMut gives no alignment guarantees, so only run when the allocation happens to be aligned.
+    if data.as_ptr() as usize % 8 != 0 {
+        return;
+    }
+
+    let mut ring = data.try_get_elems_aligned::<[u64]>(2).unwrap();
+    ring[1] = 7;
+    assert_eq!(data, DATA.0[16..]);
+}
+
+#[test]
+fn try_peek_aligned() {
+    let data = Bytes::from_static(&DATA.0);
+    let header = data.try_peek_aligned::<RingHeader>().unwrap();
+    assert_eq!(header.tail, ne_u64(&DATA.0[8..16]));
+
+    let slots = data.try_peek_elems_aligned::<[u32]>(8).unwrap();
+    assert_eq!(slots.len(), 8);
+
+    assert_eq!(data, DATA.0[..]);
+}
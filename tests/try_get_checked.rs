@@ -0,0 +1,95 @@
+use core::num::NonZeroU8;
+
+use bytes::{Bytes, BytesMut};
+use zerocopy::{network_endian, Immutable, KnownLayout, TryFromBytes, Unaligned};
+use zerocopy_buf::{Error, ZeroCopyBuf};
+
+#[derive(TryFromBytes, KnownLayout, Immutable, Unaligned, PartialEq, Debug)]
+#[repr(u8)]
+enum Opcode {
+    Request = 1,
+    Reply = 2,
+}
+
+#[derive(TryFromBytes, KnownLayout, Immutable, Unaligned, PartialEq, Debug)]
+#[repr(C)]
+struct ArpHeader {
+    opcode: Opcode,
+    broadcast: bool,
+    hops: NonZeroU8,
+    len: network_endian::U16,
+}
+
+#[derive(TryFromBytes, KnownLayout, Immutable, Unaligned, Debug)]
+#[repr(C)]
+struct ArpPacket {
+    header: ArpHeader,
+    flags: [bool],
+}
+
+#[test]
+fn try_get_checked() {
+    let mut data = Bytes::from_static(b"\x02\x01\x05\x00\x10\xff\xfe");
+    let header = data.try_get_checked::<ArpHeader>().unwrap();
+
+    assert_eq!(data, b"\xff\xfe"[..]);
+    drop(data);
+
+    assert_eq!(
+        *header,
+        ArpHeader {
+            opcode: Opcode::Reply,
+            broadcast: true,
+            hops: NonZeroU8::new(5).unwrap(),
+            len: network_endian::U16::new(16),
+        }
+    );
+}
+
+#[test]
+fn try_get_checked_invalid() {
+    let header = b"\x02\x01\x00\x00\x10\xff\xfe";
+    let mut data = BytesMut::from(&header[..]);
+    let err = data.try_get_checked::<ArpHeader>().unwrap_err();
+
+    assert!(matches!(err, Error::Validity { .. }));
+    assert_eq!(data, header[..]);
+
+    let mut data: &[u8] = &header[..3];
+    let err = data.try_get_checked::<ArpHeader>().unwrap_err();
+
+    assert!(matches!(err, Error::Size { .. }));
+    assert_eq!(data, &header[..3]);
+}
+
+#[test]
+fn try_get_elems_checked() {
+    let mut data = Bytes::from_static(b"\x01\x00\x01\x00\x02\x01\x00\x01\xff");
+    let packet = data.try_get_elems_checked::<ArpPacket>(3).unwrap();
+    let packet2 = packet.clone();
+
+    assert_eq!(data, b"\xff"[..]);
+    assert_eq!(packet.header.opcode, Opcode::Request);
+    assert_eq!(packet2.flags, [true, false, true]);
+
+    let mut data = Bytes::from_static(b"\x01\x00\x01\x00\x02\x01\x02\x01\xff");
+    let err = data.try_get_elems_checked::<ArpPacket>(3).unwrap_err();
+
+    assert!(matches!(err, Error::Validity { .. }));
+    assert_eq!(data.len(), 9);
+}
+
+#[test]
+fn try_peek_checked() {
+    let header = b"\x01\x00\x01\x00\x02\x01\x00";
+    let data = Bytes::from_static(header);
+    let peeked = data.try_peek_checked::<ArpHeader>().unwrap();
+
+    assert_eq!(peeked.opcode, Opcode::Request);
+    assert!(!peeked.broadcast);
+    assert_eq!(data, header[..]);
+
+    let packet = data.try_peek_elems_checked::<ArpPacket>(2).unwrap();
+    assert_eq!(packet.flags, [true, false]);
+    assert_eq!(data, header[..]);
+}
@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+
+use bytes::{Bytes, BytesMut};
+use zerocopy::network_endian::U16;
+use zerocopy_buf::{ByteStr, Error, ZeroCopyBuf};
+
+#[test]
+fn try_get_str() {
+    let mut data = Bytes::from_static(b"\x00\x05hello\x00\x06w\xc3\xb6rld");
+
+    let len = data.try_get::<U16>().unwrap().get() as usize;
+    let hello = data.try_get_str(len).unwrap();
+    let len = data.try_get::<U16>().unwrap().get() as usize;
+    let world = data.try_get_str(len).unwrap();
+    drop(data);
+
+    assert_eq!(hello, "hello");
+    assert_eq!(&*world, "wörld");
+    assert_eq!(world.chars().count(), 5);
+    assert_eq!(format!("{hello} {world:?}"), "hello \"wörld\"");
+
+    let mut data = BytesMut::from(&b"abcdef"[..]);
+    let s = data.try_get_str(3).unwrap();
+    assert_eq!(s.as_bytes(), &b"abc"[..]);
+    assert_eq!(data, b"def"[..]);
+}
+
+#[test]
+fn try_get_str_error() {
+    let mut data = Bytes::from_static(b"ab\xe2\x82c");
+
+    let err = data.try_get_str(6).unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Size {
+            needed: 6,
+            available: 5,
+            ..
+        }
+    ));
+
+    let err = data.try_get_str(5).unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Utf8 {
+            offset: 0,
+            valid_up_to: 2,
+            ..
+        }
+    ));
+    assert_eq!(err.type_name(), "str");
+    assert_eq!(data.len(), 5);
+
+    // a code point cut short by the end of the string is invalid too
+    let err = data.try_get_str(4).unwrap_err();
+    assert!(matches!(err, Error::Utf8 { valid_up_to: 2, .. }));
+    assert_eq!(data.try_get_str(2).unwrap(), "ab");
+}
+
+#[test]
+fn byte_str() {
+    let mut map = HashMap::new();
+    map.insert(ByteStr::from_static("key"), 1);
+    assert_eq!(map.get("key"), Some(&1));
+
+    let s = ByteStr::from_utf8(Bytes::from_static(b"abc")).unwrap();
+    assert_eq!(s, ByteStr::from("abc"));
+    assert!(ByteStr::from_utf8(Bytes::from_static(b"\xff")).is_err());
+    assert_eq!(Bytes::from(s), b"abc"[..]);
+    assert_eq!(ByteStr::default(), "");
+}
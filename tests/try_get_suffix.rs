@@ -0,0 +1,92 @@
+use bytes::{Bytes, BytesMut};
+use zerocopy::{network_endian, FromBytes, Immutable, KnownLayout, Unaligned};
+use zerocopy_buf::{Error, Tracked, ZeroCopyBuf};
+
+#[derive(FromBytes, KnownLayout, Immutable, Unaligned, Debug)]
+#[repr(C)]
+struct Footer {
+    len: network_endian::U32,
+    magic: [u8; 4],
+}
+
+#[test]
+fn try_get_suffix() {
+    let mut data = Bytes::from_static(b"payload\x00\x00\x00\x07PAR1");
+    let footer = data.try_get_suffix::<Footer>().unwrap();
+
+    assert_eq!(data, b"payload"[..]);
+    drop(data);
+
+    assert_eq!(footer.len.get(), 7);
+    assert_eq!(footer.magic, *b"PAR1");
+}
+
+#[test]
+fn try_get_suffix_mut() {
+    let mut data = BytesMut::from(&b"payload\xde\xad\xbe\xef"[..]);
+    let fcs = data.try_get_suffix::<network_endian::U32>().unwrap();
+
+    assert_eq!(data, b"payload"[..]);
+    assert_eq!(fcs.get(), 0xdeadbeef);
+}
+
+#[test]
+fn try_get_suffix_elems() {
+    let mut data: &[u8] = &b"payload\x00\x01\x00\x02"[..];
+    let offsets = data
+        .try_get_suffix_elems::<[network_endian::U16]>(2)
+        .unwrap();
+
+    assert_eq!(data, b"payload");
+    assert_eq!(offsets[0].get(), 1);
+    assert_eq!(offsets[1].get(), 2);
+}
+
+#[test]
+fn try_peek_suffix() {
+    let data = Bytes::from_static(b"payload\x00\x00\x00\x07PAR1");
+    let footer = data.try_peek_suffix::<Footer>().unwrap();
+
+    assert_eq!(footer.magic, *b"PAR1");
+    assert_eq!(data.len(), 15);
+}
+
+#[test]
+fn try_get_suffix_error() {
+    let mut data = Bytes::from_static(b"PAR1");
+    let err = data.try_get_suffix::<Footer>().unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Size {
+            needed: 8,
+            available: 4,
+            ..
+        }
+    ));
+    assert_eq!(data, b"PAR1"[..]);
+
+    let mut data = BytesMut::from(&b"\x00\x01\x00"[..]);
+    let err = data
+        .try_get_suffix_elems::<[network_endian::U16]>(2)
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Size {
+            needed: 4,
+            available: 3,
+            ..
+        }
+    ));
+    assert_eq!(data, b"\x00\x01\x00"[..]);
+}
+
+#[test]
+fn tracked_suffix() {
+    let mut data = Tracked::with_trace(&b"\x01payload\xde\xad\xbe\xef"[..]);
+    data.try_get::<u8>().unwrap();
+    data.try_get_suffix::<network_endian::U32>().unwrap();
+
+    assert_eq!(data.offset(), 1);
+    assert_eq!(data.trace()[1].offset, 8);
+    assert_eq!(data.trace()[1].size, 4);
+}
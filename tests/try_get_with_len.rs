@@ -0,0 +1,119 @@
+use bytes::{Buf, Bytes, BytesMut};
+use zerocopy::{network_endian, FromBytes, Immutable, KnownLayout, Unaligned};
+use zerocopy_buf::{Error, Tracked, ZeroCopyBuf};
+
+#[derive(FromBytes, KnownLayout, Immutable, Unaligned, Debug)]
+#[repr(C)]
+struct RecordHeader {
+    kind: u8,
+    count: network_endian::U16,
+}
+
+#[derive(FromBytes, KnownLayout, Immutable, Unaligned, Debug)]
+#[repr(C)]
+struct Record {
+    header: RecordHeader,
+    values: [network_endian::U16],
+}
+
+#[test]
+fn try_get_with_len() {
+    let mut data = Bytes::from_static(b"\x01\x00\x02\x00\x0a\x00\x0b\xff");
+    let record = data
+        .try_get_with_len::<RecordHeader, Record>(|h| h.count.get() as usize)
+        .unwrap();
+
+    assert_eq!(data, b"\xff"[..]);
+    drop(data);
+
+    assert_eq!(record.header.kind, 1);
+    assert_eq!(record.values.len(), 2);
+    assert_eq!(record.values[0].get(), 0x0a);
+    assert_eq!(record.values[1].get(), 0x0b);
+}
+
+#[test]
+fn try_get_with_len_mut() {
+    let mut data = BytesMut::from(&b"\x01\x00\x01\x00\x0a"[..]);
+    let record = data
+        .try_get_with_len::<RecordHeader, Record>(|h| h.count.get() as usize)
+        .unwrap();
+
+    assert!(data.is_empty());
+    assert_eq!(record.values[0].get(), 0x0a);
+}
+
+#[test]
+fn try_get_with_len_error() {
+    // header is truncated
+    let mut data: &[u8] = &b"\x01\x00"[..];
+    let err = data
+        .try_get_with_len::<RecordHeader, Record>(|h| h.count.get() as usize)
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Size {
+            needed: 3,
+            available: 2,
+            ..
+        }
+    ));
+    assert_eq!(data.len(), 2);
+
+    // body is truncated
+    let mut data: &[u8] = &b"\x01\x00\x02\x00\x0a\x00"[..];
+    let err = data
+        .try_get_with_len::<RecordHeader, Record>(|h| h.count.get() as usize)
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Size {
+            needed: 7,
+            available: 6,
+            ..
+        }
+    ));
+    assert_eq!(data.len(), 6);
+
+    // element count overflows
+    let mut data: &[u8] = &b"\x01\x00\x02\x00\x0a\x00\x0b"[..];
+    let err = data
+        .try_get_with_len::<RecordHeader, Record>(|_| usize::MAX)
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Size {
+            needed: usize::MAX,
+            available: 7,
+            ..
+        }
+    ));
+    assert_eq!(data.len(), 7);
+}
+
+#[test]
+fn tracked_get_with_len() {
+    let mut data =
+        Tracked::with_trace(&b"\x00\x00\x00\x00\x01\x00\x01\x00\x0a\x01\x00\x02\x00"[..]);
+    data.advance(4);
+
+    let record = data
+        .try_get_with_len::<RecordHeader, Record>(|h| h.count.get() as usize)
+        .unwrap();
+    assert_eq!(record.values[0].get(), 0x0a);
+    assert_eq!((data.trace()[1].offset, data.trace()[1].size), (4, 5));
+
+    let err = data
+        .try_get_with_len::<RecordHeader, Record>(|h| h.count.get() as usize)
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Size {
+            offset: 9,
+            needed: 7,
+            available: 4,
+            ..
+        }
+    ));
+    assert_eq!(data.offset(), 9);
+}
@@ -1,4 +1,4 @@
-use bytes::Bytes;
+use bytes::{Bytes, BytesMut};
 use zerocopy::{network_endian, FromBytes, Immutable, IntoBytes, KnownLayout, Unaligned};
 use zerocopy_buf::ZeroCopyBuf;
 
@@ -25,7 +25,7 @@ struct Ipv4Addr([u8; 4]);
 fn try_peek() {
     let header_bytes =
         b"\x45\x00\x00\x14\x00\x00\x00\x00\x01\x06\x00\x00\x7f\x00\x00\x01\x7f\x00\x00\x02\xff\xfe\xfd\xfc";
-    let mut data = Bytes::from_static(header_bytes);
+    let data = Bytes::from_static(header_bytes);
     let header = data.try_peek::<Ipv4Header>().unwrap();
 
     assert_eq!(
@@ -50,8 +50,39 @@ fn try_peek() {
 #[test]
 fn try_peek_error() {
     let header = b"\x45\x00\x00\x14\x00\x00\x00\x00\x01\x06\x00\x00\x7f\x00\x00\x01\x7f\x00\x00";
-    let mut data = Bytes::from_static(header);
+    let data = Bytes::from_static(header);
     let _err = data.try_peek::<Ipv4Header>().unwrap_err();
 
     assert_eq!(data.len(), 19);
 }
+
+#[test]
+fn try_peek_shared() {
+    let header =
+        b"\x45\x00\x00\x14\x00\x00\x00\x00\x01\x06\x00\x00\x7f\x00\x00\x01\x7f\x00\x00\x02";
+    let data = Bytes::from_static(header);
+
+    fn ttl(data: &Bytes) -> u8 {
+        data.try_peek::<Ipv4Header>().unwrap().ttl
+    }
+    assert_eq!(ttl(&data), 1);
+}
+
+#[test]
+fn try_peek_mut() {
+    let header =
+        b"\x45\x00\x00\x14\x00\x00\x00\x00\x01\x06\x00\x00\x7f\x00\x00\x01\x7f\x00\x00\x02\xff";
+    let mut data = BytesMut::from(&header[..]);
+
+    let mut peeked = data.try_peek_mut::<Ipv4Header>().unwrap();
+    peeked.ttl -= 1;
+    assert_eq!(data.len(), 21);
+
+    let mut addrs = data.try_peek_elems_mut::<[Ipv4Addr]>(5).unwrap();
+    addrs[4] = Ipv4Addr([10, 0, 0, 1]);
+
+    let header = data.try_get::<Ipv4Header>().unwrap();
+    assert_eq!(header.ttl, 0);
+    assert_eq!(header.dst, Ipv4Addr([10, 0, 0, 1]));
+    assert!(data.try_peek_mut::<Ipv4Header>().is_err());
+}
@@ -25,7 +25,7 @@ struct Ipv4Addr([u8; 4]);
 fn try_peek() {
     let header_bytes =
         b"\x45\x00\x00\x14\x00\x00\x00\x00\x01\x06\x00\x00\x7f\x00\x00\x01\x7f\x00\x00\x02\x45\x00\x00\x14\x00\x00\x00\x00\x01\x06\x00\x00\x7f\x00\x00\x01\x7f\x00\x00\x02\xff\xfe\xfd\xfc";
-    let mut data = Bytes::from_static(header_bytes);
+    let data = Bytes::from_static(header_bytes);
     let header = data.try_peek_elems::<[Ipv4Header]>(2).unwrap();
 
     assert_eq!(
@@ -0,0 +1,101 @@
+use bytes::{Buf, Bytes};
+use zerocopy::network_endian::{U16, U32};
+use zerocopy_buf::{Error, Tracked, ZeroCopyReadBuf};
+
+#[test]
+fn try_peek_read() {
+    let data = Bytes::from_static(b"\x00\x03abc\xde\xad\xbe\xef");
+
+    let len = data.try_peek_read::<U16>().unwrap().get() as usize;
+    let value = data.try_peek_read_at::<U32>(2 + len).unwrap();
+
+    assert_eq!(value.get(), 0xdeadbeef);
+    assert_eq!(data.len(), 9);
+}
+
+#[test]
+fn try_peek_read_error() {
+    let data: &[u8] = &b"\x00\x03abc"[..];
+
+    let err = data.try_peek_read_at::<U32>(2).unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Size {
+            offset: 2,
+            needed: 4,
+            available: 3,
+            ..
+        }
+    ));
+
+    let err = data.try_peek_read_at::<U16>(10).unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Size {
+            offset: 10,
+            available: 0,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn tracked_peek_read() {
+    let mut data = Tracked::new(&b"\x01\x00\x02"[..]);
+    data.try_read::<u8>().unwrap();
+
+    assert_eq!(data.try_peek_read::<U16>().unwrap().get(), 2);
+    assert_eq!(data.try_peek_read_at::<U16>(1).unwrap_err().offset(), 2);
+    assert_eq!(data.offset(), 1);
+}
+
+#[test]
+fn tracked_peek_read_far() {
+    let mut data = Tracked::new(&b"\x01\x00\x02"[..]);
+    data.advance(1);
+
+    let err = data.try_peek_read_at::<u8>(usize::MAX).unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Size {
+            offset: usize::MAX,
+            available: 0,
+            ..
+        }
+    ));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn try_peek_read_chain() {
+    let a = Bytes::from_static(b"\x00\x01\x02");
+    let b = Bytes::from_static(b"\x03");
+    let c = Bytes::from_static(b"\x04\x05");
+    let data = a.chain(b).chain(c);
+
+    let value = data.try_peek_read_at::<U32>(1).unwrap();
+    assert_eq!(value.get(), 0x01020304);
+    assert_eq!(data.remaining(), 6);
+}
+
+#[cfg(not(feature = "std"))]
+#[test]
+fn try_peek_read_chain() {
+    let a = Bytes::from_static(b"\x00\x01\x02");
+    let b = Bytes::from_static(b"\x03");
+    let data = a.chain(b);
+
+    assert_eq!(data.try_peek_read::<U16>().unwrap().get(), 1);
+
+    // only the first chunk is visible without advancing
+    let err = data.try_peek_read_at::<U32>(0).unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Size {
+            needed: 4,
+            available: 3,
+            ..
+        }
+    ));
+    assert_eq!(data.remaining(), 4);
+}
@@ -0,0 +1,85 @@
+use core::num::NonZeroU16;
+
+use bytes::{Buf, Bytes};
+use zerocopy::TryFromBytes;
+use zerocopy_buf::{Error, ZeroCopyReadBuf};
+
+#[derive(TryFromBytes, PartialEq, Debug)]
+#[repr(u8)]
+enum Kind {
+    Data = 0,
+    Ack = 1,
+}
+
+#[derive(TryFromBytes, PartialEq, Debug)]
+#[repr(C)]
+struct Segment {
+    port: NonZeroU16,
+    kind: Kind,
+    urgent: bool,
+}
+
+#[test]
+fn read_checked() {
+    let port = 443u16.to_ne_bytes();
+    let mut data = Bytes::copy_from_slice(&[port[0], port[1], 1, 0, 0xff]);
+    let segment = data.try_read_checked::<Segment>().unwrap();
+
+    assert_eq!(
+        segment,
+        Segment {
+            port: NonZeroU16::new(443).unwrap(),
+            kind: Kind::Ack,
+            urgent: false,
+        }
+    );
+    assert_eq!(data, b"\xff"[..]);
+}
+
+#[test]
+fn read_checked_chunked() {
+    let port = 443u16.to_ne_bytes();
+    let mut data =
+        Bytes::copy_from_slice(&port[..1]).chain(Bytes::copy_from_slice(&[port[1], 0, 1]));
+
+    if cfg!(feature = "std") {
+        let segment = data.try_read_checked::<Segment>().unwrap();
+        assert_eq!(segment.port.get(), 443);
+        assert_eq!(segment.kind, Kind::Data);
+        assert!(segment.urgent);
+        assert_eq!(data.remaining(), 0);
+    } else {
+        let err = data.try_read_checked::<Segment>().unwrap_err();
+        assert!(matches!(err, Error::Fragmented { size: 4, .. }));
+        assert_eq!(data.remaining(), 4);
+    }
+}
+
+#[test]
+fn read_checked_invalid() {
+    let mut data = Bytes::from_static(b"\x00\x00\x01\x00");
+    let err = data.try_read_checked::<Segment>().unwrap_err();
+
+    assert!(matches!(err, Error::Validity { .. }));
+    assert_eq!(data.len(), 4);
+
+    let mut data = Bytes::from_static(b"\x01\x00\x01");
+    let err = data.try_read_checked::<Segment>().unwrap_err();
+
+    assert!(matches!(err, Error::Size { .. }));
+    assert_eq!(data.len(), 3);
+}
+
+#[test]
+fn read_checked_invalid_chunked() {
+    let mut data = Bytes::from_static(b"\x01").chain(Bytes::from_static(b"\x00\x02\x00"));
+    let err = data.try_read_checked::<Segment>().unwrap_err();
+
+    if cfg!(feature = "std") {
+        assert!(matches!(err, Error::Validity { .. }));
+    } else {
+        // only the first chunk is visible without advancing.
+        assert!(matches!(err, Error::Fragmented { size: 4, .. }));
+    }
+    assert_eq!(data.remaining(), 4);
+}
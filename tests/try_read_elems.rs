@@ -0,0 +1,78 @@
+use bytes::{Buf, Bytes};
+use zerocopy::{network_endian, FromBytes};
+use zerocopy_buf::{Error, Tracked, ZeroCopyReadBuf};
+
+#[derive(FromBytes, PartialEq, Debug)]
+#[repr(C)]
+struct Record {
+    id: network_endian::U16,
+    value: network_endian::U32,
+}
+
+fn record(id: u16, value: u32) -> Record {
+    Record {
+        id: network_endian::U16::new(id),
+        value: network_endian::U32::new(value),
+    }
+}
+
+#[test]
+fn try_read_elems_chain() {
+    let a = Bytes::from_static(b"\x00\x01\x00\x00\x00\x0a\x00\x02\x00");
+    let b = Bytes::from_static(b"\x00\x00\x14\x00\x03\x00\x00\x00\x1e\xff");
+    let mut data = a.chain(b);
+
+    let records = data.try_read_elems::<Record>(3).unwrap();
+    assert_eq!(records, [record(1, 10), record(2, 20), record(3, 30)]);
+    assert_eq!(data.remaining(), 1);
+}
+
+#[test]
+fn try_read_boxed_slice() {
+    let mut data: &[u8] = &b"\x00\x01\x00\x00\x00\x0a\x00\x02\x00\x00\x00\x14"[..];
+    let records = data.try_read_boxed_slice::<Record>(2).unwrap();
+    assert_eq!(*records, [record(1, 10), record(2, 20)]);
+    assert!(data.is_empty());
+}
+
+#[test]
+fn try_read_elems_error() {
+    let mut data: &[u8] = &b"\x00\x01\x00\x00\x00\x0a\x00\x02"[..];
+
+    let err = data.try_read_elems::<Record>(2).unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Size {
+            needed: 12,
+            available: 8,
+            ..
+        }
+    ));
+
+    // an untrusted count must not overflow or allocate
+    let err = data.try_read_elems::<Record>(usize::MAX).unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Size {
+            needed: usize::MAX,
+            available: 8,
+            ..
+        }
+    ));
+
+    assert_eq!(data.len(), 8);
+}
+
+#[test]
+fn tracked_read_elems() {
+    let mut data = Tracked::with_trace(&b"\xff\x00\x01\x00\x02"[..]);
+    data.try_read::<u8>().unwrap();
+
+    let values = data.try_read_elems::<network_endian::U16>(2).unwrap();
+    assert_eq!(values[1].get(), 2);
+    assert_eq!(data.trace()[1].offset, 1);
+    assert_eq!(data.trace()[1].size, 4);
+
+    let err = data.try_read_elems::<network_endian::U16>(1).unwrap_err();
+    assert_eq!(err.offset(), 5);
+}
@@ -0,0 +1,55 @@
+use bytes::BytesMut;
+use zerocopy::byteorder::{BigEndian, LittleEndian};
+use zerocopy_buf::{Error, Tracked, ZeroCopyBufMut, ZeroCopyReadBuf};
+
+#[test]
+fn try_read_endian() {
+    let mut data: &[u8] = &b"\x01\x02\x03\x04\x01\x02\x03\x04\xff"[..];
+
+    assert_eq!(data.try_read_be::<u32>().unwrap(), 0x01020304);
+    assert_eq!(data.try_read_le::<u32>().unwrap(), 0x04030201);
+    assert_eq!(data.try_read_endian::<BigEndian, i8>().unwrap(), -1);
+    assert!(data.is_empty());
+}
+
+#[test]
+fn try_read_endian_error() {
+    let mut data: &[u8] = &b"\x01\x02\x03"[..];
+
+    let err = data.try_read_le::<u64>().unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Size {
+            needed: 8,
+            available: 3,
+            ..
+        }
+    ));
+    assert_eq!(data.len(), 3);
+}
+
+#[test]
+fn write_endian() {
+    let mut data = BytesMut::new();
+    data.write_be(0x0102u16);
+    data.write_le(-2i32);
+    data.write_endian::<LittleEndian, _>(1.5f64);
+    data.write_be(7u8);
+
+    assert_eq!(data.try_read_be::<u16>().unwrap(), 0x0102);
+    assert_eq!(data.try_read_le::<i32>().unwrap(), -2);
+    assert_eq!(data.try_read_le::<f64>().unwrap(), 1.5);
+    assert_eq!(data.try_read_be::<u8>().unwrap(), 7);
+    assert!(data.is_empty());
+}
+
+#[test]
+fn tracked_read_endian() {
+    let mut data = Tracked::with_trace(&b"\x00\x01\x00"[..]);
+    assert_eq!(data.try_read_be::<u16>().unwrap(), 1);
+
+    let err = data.try_read_le::<u16>().unwrap_err();
+    assert_eq!(err.offset(), 2);
+    assert_eq!(data.trace().len(), 1);
+    assert_eq!(data.trace()[0].size, 2);
+}
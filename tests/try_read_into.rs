@@ -0,0 +1,90 @@
+use bytes::{Buf, Bytes};
+use zerocopy::{network_endian, FromBytes, FromZeros, Immutable, KnownLayout};
+use zerocopy_buf::{Error, ZeroCopyReadBuf};
+
+#[derive(FromBytes, KnownLayout, Immutable, Debug)]
+#[repr(C)]
+struct State {
+    generation: network_endian::U32,
+    table: [u8; 4096],
+}
+
+#[derive(FromBytes, KnownLayout, Immutable, Debug)]
+#[repr(C)]
+struct Packet {
+    len: network_endian::U16,
+    body: [network_endian::U16],
+}
+
+#[test]
+fn try_read_into() {
+    let mut state = State::new_box_zeroed().unwrap();
+
+    let mut msg = vec![0, 0, 0, 7];
+    msg.extend((0..4096).map(|i| i as u8));
+    let (a, b) = msg.split_at(1000);
+    let mut data = a.chain(b);
+
+    data.try_read_into(&mut *state).unwrap();
+    assert_eq!(state.generation.get(), 7);
+    assert_eq!(state.table[4095], 255);
+    assert!(!data.has_remaining());
+}
+
+#[test]
+fn try_read_into_error() {
+    let mut values = [network_endian::U16::new(1); 3];
+    let mut data: &[u8] = &b"\x00\x02\x00\x03"[..];
+
+    let err = data.try_read_into(&mut values[..]).unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Size {
+            needed: 6,
+            available: 4,
+            ..
+        }
+    ));
+    assert_eq!(values, [network_endian::U16::new(1); 3]);
+    assert_eq!(data.len(), 4);
+
+    data.try_read_into(&mut values[..2]).unwrap();
+    assert_eq!(values[..2], [2, 3].map(network_endian::U16::new));
+}
+
+#[test]
+fn try_read_boxed() {
+    let a = Bytes::from_static(b"\x00\x02\x00");
+    let b = Bytes::from_static(b"\x0a\x00\x0b\xff");
+    let mut data = a.chain(b);
+
+    let packet = data.try_read_boxed::<Packet>(2).unwrap();
+    assert_eq!(packet.len.get(), 2);
+    assert_eq!(packet.body, [10, 11].map(network_endian::U16::new));
+    assert_eq!(data.remaining(), 1);
+}
+
+#[test]
+fn try_read_boxed_error() {
+    let mut data: &[u8] = &b"\x00\x02\x00\x0a"[..];
+
+    let err = data.try_read_boxed::<Packet>(2).unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Size {
+            needed: 6,
+            available: 4,
+            ..
+        }
+    ));
+
+    let err = data.try_read_boxed::<Packet>(usize::MAX).unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Size {
+            needed: usize::MAX,
+            ..
+        }
+    ));
+    assert_eq!(data.len(), 4);
+}
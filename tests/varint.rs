@@ -0,0 +1,137 @@
+use bytes::{Buf, Bytes, BytesMut};
+use zerocopy_buf::{Error, Parsed, Tracked, ZeroCopyBuf, ZeroCopyBufMut, ZeroCopyReadBuf};
+
+#[test]
+fn uvarint_roundtrip() {
+    let values = [0, 1, 127, 128, 300, 16383, 16384, u32::MAX as u64, u64::MAX];
+
+    let mut data = BytesMut::new();
+    for v in values {
+        data.write_uvarint(v);
+    }
+    assert_eq!(&data[..4], b"\x00\x01\x7f\x80");
+
+    for v in values {
+        assert_eq!(data.try_read_uvarint::<u64>().unwrap(), v);
+    }
+    assert!(data.is_empty());
+}
+
+#[test]
+fn svarint_roundtrip() {
+    let values = [0, -1, 1, -2, 63, -64, 64, i64::MIN, i64::MAX];
+
+    let mut data = BytesMut::new();
+    for v in values {
+        data.write_svarint(v);
+    }
+    assert_eq!(&data[..6], b"\x00\x01\x02\x03\x7e\x7f");
+
+    for v in values {
+        assert_eq!(data.try_read_svarint::<i64>().unwrap(), v);
+    }
+    assert!(data.is_empty());
+}
+
+#[test]
+fn varint_overflow() {
+    let mut data: &[u8] = &b"\x80\x02"[..];
+    let err = data.try_read_uvarint::<u8>().unwrap_err();
+    assert!(matches!(err, Error::Validity { .. }));
+    assert_eq!(err.type_name(), "u8");
+    assert_eq!(data.len(), 2);
+    assert_eq!(data.try_read_uvarint::<u16>().unwrap(), 256);
+
+    // 2^64
+    let mut data: &[u8] = &b"\x80\x80\x80\x80\x80\x80\x80\x80\x80\x02"[..];
+    let err = data.try_read_uvarint::<u64>().unwrap_err();
+    assert!(matches!(err, Error::Validity { .. }));
+
+    let mut data: &[u8] = &b"\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x01"[..];
+    let err = data.try_read_uvarint::<u64>().unwrap_err();
+    assert!(matches!(err, Error::Validity { .. }));
+
+    let mut data: &[u8] = &b"\x80\x80\x80\x80\x10"[..];
+    data.try_read_svarint::<i32>().unwrap_err();
+    assert_eq!(data.try_read_svarint::<i64>().unwrap(), i32::MAX as i64 + 1);
+}
+
+#[test]
+fn varint_overlong() {
+    let mut data: &[u8] = &b"\x81\x00"[..];
+    let err = data.try_read_uvarint::<u32>().unwrap_err();
+    assert!(matches!(err, Error::Validity { .. }));
+    assert_eq!(data.len(), 2);
+
+    let mut data: &[u8] = &b"\x00"[..];
+    assert_eq!(data.try_read_uvarint::<u32>().unwrap(), 0);
+}
+
+#[test]
+fn varint_incomplete() {
+    let mut data: &[u8] = &b"\xac"[..];
+    let err = data.try_read_uvarint::<u32>().unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Size {
+            needed: 2,
+            available: 1,
+            ..
+        }
+    ));
+    assert_eq!(data.len(), 1);
+
+    let mut data: &[u8] = &b"\x80\x80"[..];
+    assert_eq!(
+        data.parse_streaming(|b| b.try_read_uvarint::<u32>()),
+        Parsed::Incomplete { needed: 1 }
+    );
+    let mut data: &[u8] = &b"\x80\x80\x01"[..];
+    assert_eq!(
+        data.parse_streaming(|b| b.try_read_uvarint::<u32>()),
+        Parsed::Complete(1 << 14)
+    );
+}
+
+#[test]
+fn varint_chunks() {
+    let a = Bytes::from_static(b"\xff");
+    let b = Bytes::from_static(b"\xff\x03\x05");
+    let mut data = a.chain(b);
+
+    assert_eq!(data.try_read_uvarint::<u32>().unwrap(), 0xffff);
+    assert_eq!(data.try_read_uvarint::<u32>().unwrap(), 5);
+
+    let mut data = Bytes::from_static(b"\x80").chain(Bytes::from_static(b"\x00\x01"));
+    let err = data.try_read_uvarint::<u32>().unwrap_err();
+    assert!(matches!(err, Error::Validity { .. }));
+    if cfg!(feature = "std") {
+        assert_eq!(data.remaining(), 3);
+    } else {
+        // only the first chunk is visible without advancing.
+        assert_eq!(data.remaining(), 1);
+    }
+
+    let mut data = Bytes::from_static(b"\x80").chain(Bytes::from_static(b"\x80"));
+    let err = data.try_read_uvarint::<u32>().unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Size {
+            needed: 3,
+            available: 2,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn tracked_varint() {
+    let mut data = Tracked::with_trace(&b"\xac\x02\x03\x80"[..]);
+    assert_eq!(data.try_read_uvarint::<u32>().unwrap(), 300);
+    assert_eq!(data.try_read_svarint::<i32>().unwrap(), -2);
+
+    let err = data.try_read_uvarint::<u32>().unwrap_err();
+    assert_eq!(err.offset(), 3);
+    assert_eq!(data.trace().len(), 2);
+    assert_eq!((data.trace()[1].offset, data.trace()[1].size), (2, 1));
+}